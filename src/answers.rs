@@ -0,0 +1,147 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use crate::parse::{self, ParseError};
+
+/// Default location of the recorded answers
+pub const DEFAULT_ANSWERS_PATH: &str = "answers.toml";
+
+/// Expected answers per day and part, as recorded in `answers.toml`
+///
+/// The file is a small subset of TOML: one `[dayN]` table per day holding
+/// `part_1` and `part_2` keys, whose values are integers or strings. Lines
+/// starting with `#` are comments.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers(BTreeMap<(u32, u32), String>);
+
+/// Outcome of checking an answer against the recorded one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+impl Answers {
+    /// Recorded answer of a part, if any
+    pub fn get(&self, day: u32, part: u32) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Records the answer of a part
+    pub fn insert(&mut self, day: u32, part: u32, answer: String) {
+        self.0.insert((day, part), answer);
+    }
+
+    /// Compares an answer with the recorded one
+    pub fn check(&self, day: u32, part: u32, answer: &str) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Missing,
+        }
+    }
+}
+
+impl FromStr for Answers {
+    type Err = ParseError;
+
+    fn from_str(content: &str) -> Result<Self, Self::Err> {
+        let mut answers = Answers::default();
+        let mut day = None;
+        for (i, line) in content.lines().enumerate() {
+            let entry = line.trim();
+            let result = if entry.is_empty() || entry.starts_with('#') {
+                Ok(())
+            } else if let Some(table) = entry.strip_prefix('[') {
+                table
+                    .strip_suffix(']')
+                    .and_then(|table| table.strip_prefix("day"))
+                    .and_then(|number| number.parse().ok())
+                    .map(|number| day = Some(number))
+                    .ok_or_else(|| ParseError::invalid(entry, "a table `[dayN]`"))
+            } else {
+                parse_entry(entry).and_then(|(part, answer)| match day {
+                    Some(day) => {
+                        answers.insert(day, part, answer);
+                        Ok(())
+                    }
+                    None => Err(ParseError::invalid(entry, "a table `[dayN]` first")),
+                })
+            };
+            result.map_err(|err| err.within(line, entry).at_line(i + 1))?;
+        }
+        Ok(answers)
+    }
+}
+
+// Parses a `part_N = value` entry
+fn parse_entry(entry: &str) -> Result<(u32, String), ParseError> {
+    let (key, value) = parse::split_once(entry, "=", "an entry `part_N = answer`")?;
+    let (key, value) = (key.trim(), value.trim());
+    let part = match key {
+        "part_1" => 1,
+        "part_2" => 2,
+        _ => return Err(ParseError::invalid(key, "`part_1` or `part_2`").within(entry, key)),
+    };
+    let answer = match value.strip_prefix('"') {
+        Some(quoted) => quoted
+            .strip_suffix('"')
+            .ok_or_else(|| ParseError::missing(value, "a closing `\"`")),
+        None if !value.is_empty() && value.chars().all(|c| c.is_ascii_digit() || c == '-') => {
+            Ok(value)
+        }
+        None => Err(ParseError::invalid(value, "an integer or a string")),
+    };
+    let answer = answer.map_err(|err| err.within(entry, value))?;
+    Ok((part, answer.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_with_comments_strings_and_integers() {
+        let content = "# recorded answers\n\n[day1]\npart_1 = 7\n  # indented comment\npart_2 = \"five\"\n\n[day2]\npart_1 = -150\n";
+        let answers: Answers = content.parse().unwrap();
+        assert_eq!(answers.get(1, 1), Some("7"));
+        assert_eq!(answers.get(1, 2), Some("five"));
+        assert_eq!(answers.get(2, 1), Some("-150"));
+        assert_eq!(answers.get(2, 2), None);
+    }
+
+    #[test]
+    fn entries_need_a_day_table() {
+        let err = "part_1 = 7\n".parse::<Answers>().unwrap_err();
+        assert_eq!((err.line(), err.column()), (1, 1));
+        let err = "[day1]\n[days]\n".parse::<Answers>().unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 1));
+        let err = "[day1]\n  part_3 = 7\n".parse::<Answers>().unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 3));
+    }
+
+    #[test]
+    fn unclosed_quote_points_past_the_value() {
+        let err = "[day1]\n  part_1 = \"abc\n".parse::<Answers>().unwrap_err();
+        assert!(matches!(err, ParseError::Missing { .. }));
+        assert_eq!((err.line(), err.column()), (2, 16));
+        let err = "[day1]\npart_1 = 1.5\n".parse::<Answers>().unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 10));
+    }
+
+    #[test]
+    fn verdicts() {
+        let answers: Answers = "[day3]\npart_1 = 198\n".parse().unwrap();
+        assert_eq!(answers.check(3, 1, "198"), Verdict::Pass);
+        assert_eq!(
+            answers.check(3, 1, "197"),
+            Verdict::Fail {
+                expected: "198".to_string()
+            }
+        );
+        assert_eq!(answers.check(3, 2, "230"), Verdict::Missing);
+    }
+}
@@ -0,0 +1,145 @@
+use std::process::ExitCode;
+
+use advent_of_code_2021::day01::{
+    self, sparkline, windowed_increases, Day01, IncreaseCounter, Profile, StreamError,
+};
+use advent_of_code_2021::input::InputSource;
+use advent_of_code_2021::solution::Solution;
+
+use crate::{json_string, parse, print_table, read, terminal_width, Format, Options};
+
+/// Window sizes compared when none is given on the command line
+const DEFAULT_WINDOWS: [usize; 2] = [1, 3];
+
+/// Counts the depth increases of a sonar sweep over sliding windows of every given size
+pub fn windows(source: &InputSource, windows: &[usize]) -> ExitCode {
+    let Some(input) = read(source).and_then(|content| parse(source, &content, Day01::parse)) else {
+        return ExitCode::FAILURE;
+    };
+    let windows = or_default(windows);
+    let rows: Vec<[String; 2]> = windows
+        .iter()
+        .map(|&window| {
+            [
+                window.to_string(),
+                windowed_increases(&input.0, window).to_string(),
+            ]
+        })
+        .collect();
+    print_table(["window", "increases"], &rows);
+    ExitCode::SUCCESS
+}
+
+/// Prints statistics of a sonar sweep followed by a sparkline of its depths
+pub fn report(source: &InputSource, width: Option<usize>) -> ExitCode {
+    let Some(input) = read(source).and_then(|content| parse(source, &content, Day01::parse)) else {
+        return ExitCode::FAILURE;
+    };
+    let Some(profile) = Profile::new(&input.0) else {
+        eprintln!("error: {} holds no depth", source);
+        return ExitCode::FAILURE;
+    };
+    let run = &profile.longest_run;
+    let jump = match profile.largest_jump {
+        Some(jump) => format!(
+            "{:+} (readings {} to {})",
+            jump.change,
+            jump.index - 1,
+            jump.index
+        ),
+        None => "none".to_string(),
+    };
+    let rows = [
+        ["readings".to_string(), input.0.len().to_string()],
+        ["min depth".to_string(), profile.min.to_string()],
+        ["max depth".to_string(), profile.max.to_string()],
+        ["mean depth".to_string(), format!("{:.2}", profile.mean)],
+        [
+            "longest increasing run".to_string(),
+            format!(
+                "{} readings ({} to {})",
+                run.end() - run.start() + 1,
+                run.start(),
+                run.end()
+            ),
+        ],
+        ["largest jump".to_string(), jump],
+    ];
+    print_table(["statistic", "value"], &rows);
+    println!();
+    println!(
+        "{}",
+        sparkline(&input.0, width.unwrap_or_else(terminal_width))
+    );
+    ExitCode::SUCCESS
+}
+
+/// Prints the running increase counts as depths arrive, until the input ends
+pub fn stream(source: &InputSource, options: &Options) -> ExitCode {
+    let reader = match source.open() {
+        Ok(reader) => reader,
+        Err(err) => {
+            eprintln!("error: cannot read {}: {}", source, err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let windows = or_default(&options.windows);
+    let mut counter = IncreaseCounter::new(windows);
+    let names: Vec<String> = std::iter::once("depth".to_string())
+        .chain(windows.iter().map(|window| format!("window_{}", window)))
+        .collect();
+    match options.format {
+        Format::Table => println!(
+            "{}",
+            names
+                .iter()
+                .map(|name| format!("{:>10}", name))
+                .collect::<String>()
+        ),
+        Format::Csv => println!("{}", names.join(",")),
+        Format::Json => {}
+    }
+    let result = day01::stream(reader, &mut counter, |depth, counts| {
+        let values: Vec<String> = std::iter::once(depth.to_string())
+            .chain(counts.iter().map(usize::to_string))
+            .collect();
+        match options.format {
+            Format::Table => println!(
+                "{}",
+                values
+                    .iter()
+                    .map(|value| format!("{:>10}", value))
+                    .collect::<String>()
+            ),
+            Format::Csv => println!("{}", values.join(",")),
+            Format::Json => {
+                let fields: Vec<String> = names
+                    .iter()
+                    .zip(&values)
+                    .map(|(name, value)| format!("{}: {}", json_string(name), value))
+                    .collect();
+                println!("{{{}}}", fields.join(", "));
+            }
+        }
+    });
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(StreamError::Io(err)) => {
+            eprintln!("error: cannot read {}: {}", source, err);
+            ExitCode::FAILURE
+        }
+        Err(StreamError::Parse(err)) => {
+            eprintln!("error: {}: {}", source, err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// Window sizes given on the command line, or the default ones
+fn or_default(windows: &[usize]) -> &[usize] {
+    if windows.is_empty() {
+        &DEFAULT_WINDOWS
+    } else {
+        windows
+    }
+}
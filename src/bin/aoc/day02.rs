@@ -0,0 +1,169 @@
+use std::process::ExitCode;
+
+use advent_of_code_2021::day02::{self, Accumulator, Day02, Input, Model, State, Submarine};
+use advent_of_code_2021::input::InputSource;
+use advent_of_code_2021::solution::Solution;
+
+use crate::{parse, print_records, print_table, read, Field, Format, Options};
+
+/// Accumulator used when none is given on the command line
+const DEFAULT_ACCUMULATOR: &str = "i128";
+
+// Calls a generic command with the accumulator named on the command line
+macro_rules! with_accumulator {
+    ($options:expr, $command:ident($($arg:expr),*)) => {
+        match $options.accumulator.as_deref().unwrap_or(DEFAULT_ACCUMULATOR) {
+            "i32" => $command::<i32>($($arg),*),
+            "i64" => $command::<i64>($($arg),*),
+            "i128" => $command::<i128>($($arg),*),
+            other => Err(format!("unknown accumulator `{}`", other)),
+        }
+    };
+}
+
+/// Steers the submarine under every given model and prints where it ends up
+pub fn dive(source: &InputSource, options: &Options) -> Result<ExitCode, String> {
+    with_accumulator!(options, dive_with(source, &options.models))
+}
+
+fn dive_with<T: Accumulator>(source: &InputSource, names: &[String]) -> Result<ExitCode, String> {
+    let models: Vec<(&str, &dyn Model<T>)> = if names.is_empty() {
+        day02::models().to_vec()
+    } else {
+        names
+            .iter()
+            .map(|name| {
+                day02::model(name)
+                    .map(|model| (name.as_str(), model))
+                    .ok_or_else(|| format!("unknown model `{}`", name))
+            })
+            .collect::<Result<_, _>>()?
+    };
+    let Some(input) = read(source).and_then(|content| parse(source, &content, Day02::parse)) else {
+        return Ok(ExitCode::FAILURE);
+    };
+    let mut rows = vec![];
+    let mut overflowed = vec![];
+    for (name, model) in models {
+        let state = match Submarine::new(model).run(&input.0) {
+            Ok(state) => state,
+            Err(err) => {
+                eprintln!("error: {}: {} under the `{}` model", source, err, name);
+                return Ok(ExitCode::FAILURE);
+            }
+        };
+        rows.push([
+            name.to_string(),
+            state.horizontal.to_string(),
+            state.depth.to_string(),
+            state.aim.to_string(),
+            match state.product() {
+                Some(product) => product.to_string(),
+                None => {
+                    overflowed.push(name);
+                    "overflow".to_string()
+                }
+            },
+        ]);
+    }
+    print_table(["model", "horizontal", "depth", "aim", "product"], &rows);
+    for name in &overflowed {
+        eprintln!(
+            "error: {}: {} under the `{}` model",
+            source,
+            day02::PRODUCT_OVERFLOW,
+            name
+        );
+    }
+    if overflowed.is_empty() {
+        Ok(ExitCode::SUCCESS)
+    } else {
+        Ok(ExitCode::FAILURE)
+    }
+}
+
+/// Prints the state of the submarine after every command
+pub fn trace(source: &InputSource, options: &Options) -> Result<ExitCode, String> {
+    with_accumulator!(options, trace_with(source, options))
+}
+
+fn trace_with<T: Accumulator>(source: &InputSource, options: &Options) -> Result<ExitCode, String> {
+    let model = single_model::<T>(&options.models)?;
+    let Some((input, states)) = course(source, model) else {
+        return Ok(ExitCode::FAILURE);
+    };
+    let commands = std::iter::once(String::new()).chain(input.0.iter().map(ToString::to_string));
+    let records: Vec<[String; 5]> = states
+        .iter()
+        .zip(commands)
+        .enumerate()
+        .map(|(step, (state, command))| {
+            [
+                step.to_string(),
+                command,
+                state.horizontal.to_string(),
+                state.depth.to_string(),
+                state.aim.to_string(),
+            ]
+        })
+        .collect();
+    let names = ["step", "command", "horizontal", "depth", "aim"];
+    match options.format {
+        Format::Table => print_table(names, &records),
+        Format::Csv | Format::Json => print_records(
+            options.format,
+            names,
+            &records
+                .into_iter()
+                .map(|[step, command, horizontal, depth, aim]| {
+                    [
+                        Field::Number(step),
+                        Field::Text(command),
+                        Field::Number(horizontal),
+                        Field::Number(depth),
+                        Field::Number(aim),
+                    ]
+                })
+                .collect::<Vec<_>>(),
+        ),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Prints the course of the submarine as an SVG picture
+pub fn svg(source: &InputSource, options: &Options) -> Result<ExitCode, String> {
+    with_accumulator!(options, svg_with(source, &options.models))
+}
+
+fn svg_with<T: Accumulator>(source: &InputSource, names: &[String]) -> Result<ExitCode, String> {
+    let model = single_model::<T>(names)?;
+    let Some((_, states)) = course(source, model) else {
+        return Ok(ExitCode::FAILURE);
+    };
+    print!("{}", day02::svg(&states));
+    Ok(ExitCode::SUCCESS)
+}
+
+// Model named on the command line, the one of the second part by default
+fn single_model<T: Accumulator>(names: &[String]) -> Result<&'static dyn Model<T>, String> {
+    match names {
+        [] => Ok(&day02::Aim),
+        [name] => day02::model(name).ok_or_else(|| format!("unknown model `{}`", name)),
+        _ => Err("a single model can be given".to_string()),
+    }
+}
+
+// Commands of the course and every state the submarine goes through
+fn course<T: Accumulator>(
+    source: &InputSource,
+    model: &dyn Model<T>,
+) -> Option<(Input, Vec<State<T>>)> {
+    let input = read(source).and_then(|content| parse(source, &content, Day02::parse))?;
+    match Submarine::new(model).trace(&input.0) {
+        Ok(states) => Some((input, states)),
+        Err(err) => {
+            eprintln!("error: {}: {}", source, err);
+            None
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use std::process::ExitCode;
+
+use advent_of_code_2021::day03::{self, Day03, Tie, Trie};
+use advent_of_code_2021::input::InputSource;
+use advent_of_code_2021::solution::Solution;
+
+use crate::{null, parse, print_records, print_table, read, Field, Format, Options};
+
+/// Prints the bits counted in every column, then the rates and ratings they lead to
+///
+/// Tables show the columns and the ratings apart, while machine formats hold
+/// both in a single list of records told apart by their `kind`.
+pub fn report(source: &InputSource, options: &Options) -> ExitCode {
+    let Some(input) = read(source).and_then(|content| parse(source, &content, Day03::parse)) else {
+        return ExitCode::FAILURE;
+    };
+    let policy = options.tie_break;
+    let columns: Vec<[String; 4]> = day03::columns(&input)
+        .into_iter()
+        .map(|column| {
+            let majority = match column.majority(policy) {
+                Ok(one) => (one as u8).to_string(),
+                Err(_) => "tie".to_string(),
+            };
+            [
+                (column.index + 1).to_string(),
+                column.ones.to_string(),
+                column.zeros.to_string(),
+                majority,
+            ]
+        })
+        .collect();
+    let power = day03::power(&input, policy).map(Some);
+    let life_support = day03::life_support(&Trie::new(&input), policy);
+    let failed = power.is_err() || life_support.is_err();
+    let mut ratings = summarise(["gamma", "epsilon", "power"], power);
+    ratings.extend(summarise(["oxygen", "co2", "life support"], life_support));
+    match options.format {
+        Format::Table => {
+            print_table(["column", "ones", "zeros", "majority"], &columns);
+            println!();
+            print_table(
+                ["rating", "value"],
+                &ratings
+                    .into_iter()
+                    .map(|(name, value)| {
+                        let (Field::Number(value) | Field::Text(value)) = value;
+                        [name, value]
+                    })
+                    .collect::<Vec<_>>(),
+            );
+        }
+        Format::Csv | Format::Json => {
+            let null = || Field::Number(null(options.format));
+            let columns = columns.into_iter().map(|[index, ones, zeros, majority]| {
+                [
+                    Field::Text("column".to_string()),
+                    Field::Text(index),
+                    Field::Number(ones),
+                    Field::Number(zeros),
+                    Field::Text(majority),
+                ]
+            });
+            let ratings = ratings.into_iter().map(|(name, value)| {
+                [
+                    Field::Text("rating".to_string()),
+                    Field::Text(name),
+                    null(),
+                    null(),
+                    value,
+                ]
+            });
+            print_records(
+                options.format,
+                ["kind", "name", "ones", "zeros", "value"],
+                &columns.chain(ratings).collect::<Vec<_>>(),
+            );
+        }
+    }
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+// Names and values of two ratings and their product, or why they are missing
+fn summarise(names: [&str; 3], ratings: Result<Option<(u64, u64)>, Tie>) -> Vec<(String, Field)> {
+    let values = match ratings {
+        Ok(Some((first, second))) => [
+            first.to_string(),
+            second.to_string(),
+            (first as u128 * second as u128).to_string(),
+        ]
+        .map(Field::Number),
+        Ok(None) => [(); 3].map(|_| Field::Text("none".to_string())),
+        Err(tie) => [(); 3].map(|_| Field::Text(format!("error: {}", tie))),
+    };
+    names.into_iter().map(str::to_string).zip(values).collect()
+}
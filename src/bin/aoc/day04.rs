@@ -0,0 +1,386 @@
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::process::ExitCode;
+use std::str::FromStr;
+
+use advent_of_code_2021::day04::{self, Day04, Game, Input, WinRule, STANDARD_RULES};
+use advent_of_code_2021::input::InputSource;
+use advent_of_code_2021::solution::Solution;
+
+use crate::{
+    null, parse, print_records, print_table, read, terminal_width, Field, Format, Options,
+};
+
+/// Spaces between boards shown side by side
+const GAP: usize = 2;
+
+/// Plays bingo under the given rules and ranks the boards in the order they win
+pub fn play(source: &InputSource, options: &Options) -> ExitCode {
+    let Some(input) = read(source).and_then(|content| parse(source, &content, Day04::parse)) else {
+        return ExitCode::FAILURE;
+    };
+    warn_inapplicable(&input, rules(options));
+    let ranking = day04::simulate(&input, rules(options));
+    let mut rows: Vec<[Option<String>; 6]> = ranking
+        .wins
+        .iter()
+        .enumerate()
+        .map(|(rank, win)| {
+            [
+                (rank + 1).to_string(),
+                win.board_index.to_string(),
+                win.draw_index.to_string(),
+                win.winning_number.to_string(),
+                win.unmarked_sum.to_string(),
+                win.score.to_string(),
+            ]
+            .map(Some)
+        })
+        .collect();
+    rows.extend(
+        ranking
+            .losers
+            .iter()
+            .map(|board_index| [None, Some(board_index.to_string()), None, None, None, None]),
+    );
+    let names = [
+        "rank",
+        "board_index",
+        "draw_index",
+        "winning_number",
+        "unmarked_sum",
+        "score",
+    ];
+    match options.format {
+        Format::Table => print_table(
+            names,
+            &rows
+                .into_iter()
+                .map(|row| row.map(|value| value.unwrap_or_else(|| "-".to_string())))
+                .collect::<Vec<_>>(),
+        ),
+        Format::Csv | Format::Json => print_records(
+            options.format,
+            names,
+            &rows
+                .into_iter()
+                .map(|row| {
+                    row.map(|value| match value {
+                        Some(value) => Field::Number(value),
+                        None => Field::Number(null(options.format)),
+                    })
+                })
+                .collect::<Vec<_>>(),
+        ),
+    }
+    ExitCode::SUCCESS
+}
+
+/// Replays a game draw by draw, following the controls read from the standard input
+pub fn replay(source: &InputSource, options: &Options) -> Result<ExitCode, String> {
+    if *source == InputSource::Stdin {
+        return Err("the replay reads its controls from the standard input".to_string());
+    }
+    let Some(input) = read(source).and_then(|content| parse(source, &content, Day04::parse)) else {
+        return Ok(ExitCode::FAILURE);
+    };
+    warn_inapplicable(&input, rules(options));
+    let width = options.width.unwrap_or_else(terminal_width);
+    let highlight = io::stdout().is_terminal();
+    let mut replay = Replay::new(&input, rules(options));
+    println!("controls: [enter] or step, continue, jump <draw>, quit");
+    replay.show(width, highlight);
+    let mut controls = io::stdin().lock().lines();
+    loop {
+        print!("> ");
+        io::stdout().flush().ok();
+        let control = match controls.next() {
+            Some(Ok(line)) => line,
+            Some(Err(err)) => {
+                eprintln!("error: cannot read the controls: {}", err);
+                return Ok(ExitCode::FAILURE);
+            }
+            None => {
+                println!();
+                break;
+            }
+        };
+        match control.parse() {
+            Ok(Control::Step) => {
+                replay.step();
+            }
+            Ok(Control::Continue) => while replay.step() == Some(false) {},
+            Ok(Control::Jump(draw)) if draw < input.numbers.0.len() => replay.jump(draw),
+            Ok(Control::Jump(_)) => {
+                eprintln!("error: there are only {} draws", input.numbers.0.len());
+                continue;
+            }
+            Ok(Control::Quit) => break,
+            Err(err) => {
+                eprintln!("error: {}", err);
+                continue;
+            }
+        }
+        replay.show(width, highlight);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+// Rules given on the command line, or the puzzle ones
+fn rules(options: &Options) -> &[WinRule] {
+    if options.rules.is_empty() {
+        &STANDARD_RULES
+    } else {
+        &options.rules
+    }
+}
+
+// Warns about the rules that cannot be met on some of the boards
+fn warn_inapplicable(input: &Input, rules: &[WinRule]) {
+    let mut sizes: Vec<(usize, usize)> = input
+        .bingos
+        .iter()
+        .map(|bingo| (bingo.height(), bingo.width()))
+        .collect();
+    sizes.sort_unstable();
+    sizes.dedup();
+    for rule in rules {
+        for &(height, width) in &sizes {
+            if !rule.applies_to(height, width) {
+                eprintln!(
+                    "warning: rule `{}` cannot be met on {}x{} boards",
+                    rule, height, width
+                );
+            }
+        }
+    }
+}
+
+/// Command read from the standard input during a replay
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Control {
+    /// Draws the next number
+    Step,
+    /// Draws numbers until a board wins
+    Continue,
+    /// Goes to the state right after the given draw
+    Jump(usize),
+    Quit,
+}
+
+impl FromStr for Control {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.split_whitespace().collect::<Vec<_>>()[..] {
+            [] | ["s" | "step"] => Ok(Control::Step),
+            ["c" | "continue"] => Ok(Control::Continue),
+            ["j" | "jump", draw] => draw
+                .parse()
+                .map(Control::Jump)
+                .map_err(|_| format!("invalid draw `{}`", draw)),
+            ["q" | "quit"] => Ok(Control::Quit),
+            _ => Err(format!("unknown control `{}`", input.trim())),
+        }
+    }
+}
+
+/// Game being replayed, and the number of draws made so far
+struct Replay<'a> {
+    input: &'a Input,
+    rules: &'a [WinRule],
+    game: Game,
+    drawn: usize,
+}
+
+impl<'a> Replay<'a> {
+    fn new(input: &'a Input, rules: &'a [WinRule]) -> Self {
+        Replay {
+            input,
+            rules,
+            game: Game::new(&input.bingos, rules),
+            drawn: 0,
+        }
+    }
+
+    /// Draws the next number and announces the boards it makes win, telling
+    /// whether there were any, or `None` once every number has been drawn
+    fn step(&mut self) -> Option<bool> {
+        let Some(&number) = self.input.numbers.0.get(self.drawn) else {
+            println!("every number has been drawn");
+            return None;
+        };
+        let winners = self.game.draw(number);
+        for &board in &winners {
+            let unmarked_sum = self.game.unmarked_sum(board);
+            println!(
+                "board {} wins on draw {} with {}: unmarked sum {}, score {}",
+                board,
+                self.drawn,
+                number,
+                unmarked_sum,
+                unmarked_sum * number
+            );
+        }
+        self.drawn += 1;
+        Some(!winners.is_empty())
+    }
+
+    /// Goes to the state right after the given draw, starting over when it
+    /// was already made
+    fn jump(&mut self, draw: usize) {
+        if draw < self.drawn {
+            *self = Replay::new(self.input, self.rules);
+        }
+        while self.drawn <= draw {
+            self.step();
+        }
+    }
+
+    /// Prints the last draw and every board side by side, as many per row
+    /// as fit in the given width
+    fn show(&self, width: usize, highlight: bool) {
+        match self.drawn.checked_sub(1) {
+            Some(draw) => println!("draw {}: {}", draw, self.input.numbers.0[draw]),
+            None => println!("no number drawn yet"),
+        }
+        let blocks: Vec<(usize, Vec<String>)> = self
+            .game
+            .bingos()
+            .iter()
+            .enumerate()
+            .map(|(board, bingo)| {
+                let label = if self.game.has_won(board) {
+                    format!("board {} (won)", board)
+                } else {
+                    format!("board {}", board)
+                };
+                let plain = bingo.to_string();
+                let shown = if highlight {
+                    format!("{:#}", bingo)
+                } else {
+                    plain.clone()
+                };
+                let block_width = plain
+                    .lines()
+                    .chain([label.as_str()])
+                    .map(|line| line.chars().count())
+                    .max()
+                    .unwrap_or(0);
+                let lines = std::iter::once(format!("{:<1$}", label, block_width))
+                    .chain(plain.lines().zip(shown.lines()).map(|(plain, shown)| {
+                        format!(
+                            "{}{}",
+                            shown,
+                            " ".repeat(block_width - plain.chars().count())
+                        )
+                    }))
+                    .collect();
+                (block_width, lines)
+            })
+            .collect();
+        let mut blocks = blocks.into_iter().peekable();
+        while blocks.peek().is_some() {
+            let mut row: Vec<(usize, Vec<String>)> = vec![];
+            let mut used = 0;
+            while let Some((block_width, _)) = blocks.peek() {
+                if !row.is_empty() && used + GAP + block_width > width {
+                    break;
+                }
+                used += block_width + if row.is_empty() { 0 } else { GAP };
+                row.extend(blocks.next());
+            }
+            println!();
+            let height = row.iter().map(|(_, lines)| lines.len()).max().unwrap_or(0);
+            for i in 0..height {
+                let line: Vec<String> = row
+                    .iter()
+                    .map(|(block_width, lines)| {
+                        lines
+                            .get(i)
+                            .cloned()
+                            .unwrap_or_else(|| " ".repeat(*block_width))
+                    })
+                    .collect();
+                println!("{}", line.join(&" ".repeat(GAP)).trim_end());
+            }
+        }
+        println!();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+";
+
+    // Boards of the replay as they are shown
+    fn boards(replay: &Replay) -> Vec<String> {
+        replay
+            .game
+            .bingos()
+            .iter()
+            .map(|bingo| bingo.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn controls() {
+        assert_eq!("".parse(), Ok(Control::Step));
+        assert_eq!("  step ".parse(), Ok(Control::Step));
+        assert_eq!("c".parse(), Ok(Control::Continue));
+        assert_eq!("j 3".parse(), Ok(Control::Jump(3)));
+        assert_eq!("jump 12".parse(), Ok(Control::Jump(12)));
+        assert_eq!("q".parse(), Ok(Control::Quit));
+        assert_eq!(
+            "jump x".parse::<Control>(),
+            Err("invalid draw `x`".to_string())
+        );
+        assert_eq!(
+            "jump".parse::<Control>(),
+            Err("unknown control `jump`".to_string())
+        );
+        assert_eq!(
+            " fly away ".parse::<Control>(),
+            Err("unknown control `fly away`".to_string())
+        );
+    }
+
+    #[test]
+    fn jumping_back_starts_over() {
+        let input = Day04::parse(SAMPLE).unwrap();
+        let mut replay = Replay::new(&input, &STANDARD_RULES);
+        replay.jump(1);
+        let after_two_draws = boards(&replay);
+        replay.jump(13);
+        assert_eq!(replay.drawn, 14);
+        replay.jump(1);
+        assert_eq!(replay.drawn, 2);
+        assert_eq!(boards(&replay), after_two_draws);
+        assert!(!replay.game.has_won(0));
+    }
+
+    #[test]
+    fn won_boards_show_later_draws() {
+        let input = Day04::parse("1,2,3\n\n1 2\n3 4\n").unwrap();
+        let mut replay = Replay::new(&input, &STANDARD_RULES);
+        assert_eq!(replay.step(), Some(false));
+        assert_eq!(replay.step(), Some(true));
+        assert_eq!(replay.step(), Some(false));
+        assert_eq!(boards(&replay), ["[1][2]\n[3] 4 \n"]);
+    }
+}
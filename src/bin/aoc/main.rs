@@ -0,0 +1,751 @@
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::time::Duration;
+
+use advent_of_code_2021::answers::{Answers, Verdict, DEFAULT_ANSWERS_PATH};
+use advent_of_code_2021::day03::TieBreak;
+use advent_of_code_2021::day04::WinRule;
+use advent_of_code_2021::input::InputSource;
+use advent_of_code_2021::parse::ParseError;
+use advent_of_code_2021::registry::{self, Day, Report};
+
+const USAGE: &str = "usage: aoc run <day | from..to | all> [input file | -] [--format <format>]
+       aoc bench <day | from..to | all> [runs] [--format <format>]
+       aoc verify [day | from..to | all] [answers file]
+       aoc day1 windows [input file | -] [--window <size>]...
+       aoc day1 stream [input file | -] [--window <size>]... [--format <format>]
+       aoc day1 report [input file | -] [--width <columns>]
+       aoc day2 dive [input file | -] [--model <simple | aim>]... [--accumulator <int>]
+       aoc day2 trace [input file | -] [--model <simple | aim>] [--accumulator <int>]
+                      [--format <format>]
+       aoc day2 svg [input file | -] [--model <simple | aim>] [--accumulator <int>]
+       aoc day3 report [input file | -] [--tie <one | zero | error>] [--format <format>]
+       aoc day4 play [input file | -] [--rule <rule>]... [--format <format>]
+       aoc day4 replay [input file] [--rule <rule>]... [--width <columns>]
+
+Inputs are read from `inputs/dayN.txt`, or from the directory named by the
+AOC_INPUT_DIR environment variable. A single day can be given its own input
+file, `-` reading it from the standard input. Answers are checked against
+`answers.toml` unless another file is given. Benchmarks solve each part 10
+times unless another number of runs is given. Results are printed as a
+`table`, as `csv` or as `json` records. Options that a command does not list
+are rejected.
+
+Day-specific commands read the day's input unless another file is given.
+`day1 windows` counts depth increases over sliding windows of every given
+size, 1 and 3 by default. `day1 stream` reads depths from the standard input
+by default and prints the running counts as every depth arrives. `day1 report`
+summarises the depths and draws them as a sparkline as wide as `--width`, or
+else as the terminal. When the output is not a terminal, the width is taken
+from the COLUMNS environment variable, or is 80 columns. `day2 dive`
+steers the submarine under every given model, all of them by default.
+`day2 trace` prints the state of the submarine after every command and
+`day2 svg` draws its course, both under the `aim` model by default. Day 2
+positions are computed as `i128` unless another accumulator, `i32` or `i64`,
+is given, and commands that overflow it are reported. `day3 report` counts
+the bits of every column and computes the rates and ratings, columns with as
+many ones as zeros favouring ones unless another tie-break policy is given.
+`day4 play` ranks the boards in the order they win, boards and draws being
+numbered from 0 and boards that never win coming last. A board wins when it
+meets any given rule: `rows`, `columns`, `diagonals`, `corners`,
+`blackout` or a pattern such as `10001/01010/00100/01010/10001`. Complete
+rows and columns win by default. A warning names the rules that cannot be met
+on some board, such as a pattern larger than the board or the diagonals of a
+board that is not square. `day4 replay` draws the numbers one by one,
+showing the boards with their marked cells and announcing the winners. It
+reads its controls from the standard input: an empty line or `step` draws the
+next number, `continue` draws until a board wins, `jump <draw>` goes to the
+given draw and `quit` ends the replay.";
+
+/// Number of times each part is solved by a benchmark by default
+const DEFAULT_RUNS: usize = 10;
+
+/// Width of the output when the terminal does not tell its own
+const DEFAULT_WIDTH: usize = 80;
+
+/// How results are printed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    /// Aligned columns for humans
+    Table,
+    /// Comma separated values, durations in milliseconds
+    Csv,
+    /// Array of JSON objects, durations in milliseconds
+    Json,
+}
+
+/// Options that can be given anywhere on the command line
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Options {
+    format: Format,
+    windows: Vec<usize>,
+    width: Option<usize>,
+    models: Vec<String>,
+    accumulator: Option<String>,
+    tie_break: TieBreak,
+    rules: Vec<WinRule>,
+}
+
+/// Options of the command line, and whether each can be given more than once
+const OPTIONS: [(&str, bool); 7] = [
+    ("--format", false),
+    ("--window", true),
+    ("--width", false),
+    ("--model", true),
+    ("--accumulator", false),
+    ("--tie", false),
+    ("--rule", true),
+];
+
+impl Options {
+    /// Removes the options from the arguments, leaving the positional ones
+    ///
+    /// Options the command does not accept are rejected before their values
+    /// are parsed.
+    fn take(args: &mut Vec<String>) -> Result<Self, String> {
+        let mut given: Vec<(&str, String)> = vec![];
+        for (name, repeated) in OPTIONS {
+            while let Some(value) = take_option(args, name)? {
+                given.push((name, value));
+                if !repeated {
+                    break;
+                }
+            }
+        }
+        let positional: Vec<&str> = args.iter().map(String::as_str).collect();
+        if let Some((command, accepted)) = accepted_options(&positional) {
+            if let Some((option, _)) = given.iter().find(|(name, _)| !accepted.contains(name)) {
+                return Err(format!("`aoc {}` does not accept `{}`", command, option));
+            }
+        }
+        let values = |name: &'static str| {
+            given
+                .iter()
+                .filter(move |(option, _)| *option == name)
+                .map(|(_, value)| value.as_str())
+        };
+        let format = match values("--format").next() {
+            Some(format) => format.parse()?,
+            None => Format::Table,
+        };
+        let mut windows = vec![];
+        for window in values("--window") {
+            match window.parse() {
+                Ok(window) if window > 0 => windows.push(window),
+                _ => return Err(format!("invalid window size `{}`", window)),
+            }
+        }
+        let width = match values("--width").next() {
+            Some(width) => match width.parse() {
+                Ok(width) if width > 0 => Some(width),
+                _ => return Err(format!("invalid width `{}`", width)),
+            },
+            None => None,
+        };
+        let models = values("--model").map(str::to_string).collect();
+        let accumulator = values("--accumulator").next().map(str::to_string);
+        let tie_break = match values("--tie").next() {
+            Some(policy) => policy
+                .parse()
+                .map_err(|_| format!("unknown tie-break policy `{}`", policy))?,
+            None => TieBreak::default(),
+        };
+        let mut rules = vec![];
+        for rule in values("--rule") {
+            match rule.parse() {
+                Ok(rule) => rules.push(rule),
+                Err(err) => return Err(format!("invalid win rule `{}`: {}", rule, err)),
+            }
+        }
+        Ok(Options {
+            format,
+            windows,
+            width,
+            models,
+            accumulator,
+            tie_break,
+            rules,
+        })
+    }
+}
+
+/// Value of a record printed in a machine-readable format
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Field {
+    Number(String),
+    Text(String),
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "table" => Ok(Format::Table),
+            "csv" => Ok(Format::Csv),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("unknown format `{}`", input)),
+        }
+    }
+}
+
+/// Entry point of the runner, dispatching the command line to the requested days
+fn main() -> ExitCode {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let result = Options::take(&mut args).and_then(|options| dispatch(&args, &options));
+    result.unwrap_or_else(|err| {
+        eprintln!("error: {}", err);
+        ExitCode::from(2)
+    })
+}
+
+/// Runs the command named by the positional arguments
+fn dispatch(args: &[String], options: &Options) -> Result<ExitCode, String> {
+    let format = options.format;
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    match args[..] {
+        ["run", selection] => registry::select(selection).map(|days| run(&days, None, format)),
+        ["run", selection, input] => registry::select(selection).and_then(|days| {
+            if days.len() != 1 {
+                return Err("an input file can only be given for a single day".to_string());
+            }
+            Ok(run(&days, Some(InputSource::from_arg(input)), format))
+        }),
+        ["bench", selection] => {
+            registry::select(selection).map(|days| bench(&days, DEFAULT_RUNS, format))
+        }
+        ["bench", selection, runs] => {
+            let runs = match runs.parse() {
+                Ok(runs) if runs > 0 => runs,
+                _ => return Err(format!("invalid number of runs `{}`", runs)),
+            };
+            registry::select(selection).map(|days| bench(&days, runs, format))
+        }
+        ["verify"] => registry::select("all").map(|days| verify(&days, DEFAULT_ANSWERS_PATH)),
+        ["verify", selection] => {
+            registry::select(selection).map(|days| verify(&days, DEFAULT_ANSWERS_PATH))
+        }
+        ["verify", selection, answers] => {
+            registry::select(selection).map(|days| verify(&days, answers))
+        }
+        ["day1", "windows"] => Ok(day01::windows(&InputSource::for_day(1), &options.windows)),
+        ["day1", "windows", input] => Ok(day01::windows(
+            &InputSource::from_arg(input),
+            &options.windows,
+        )),
+        ["day1", "stream"] => Ok(day01::stream(&InputSource::Stdin, options)),
+        ["day1", "stream", input] => Ok(day01::stream(&InputSource::from_arg(input), options)),
+        ["day1", "report"] => Ok(day01::report(&InputSource::for_day(1), options.width)),
+        ["day1", "report", input] => {
+            Ok(day01::report(&InputSource::from_arg(input), options.width))
+        }
+        ["day2", "dive"] => day02::dive(&InputSource::for_day(2), options),
+        ["day2", "dive", input] => day02::dive(&InputSource::from_arg(input), options),
+        ["day2", "trace"] => day02::trace(&InputSource::for_day(2), options),
+        ["day2", "trace", input] => day02::trace(&InputSource::from_arg(input), options),
+        ["day2", "svg"] => day02::svg(&InputSource::for_day(2), options),
+        ["day2", "svg", input] => day02::svg(&InputSource::from_arg(input), options),
+        ["day3", "report"] => Ok(day03::report(&InputSource::for_day(3), options)),
+        ["day3", "report", input] => Ok(day03::report(&InputSource::from_arg(input), options)),
+        ["day4", "play"] => Ok(day04::play(&InputSource::for_day(4), options)),
+        ["day4", "play", input] => Ok(day04::play(&InputSource::from_arg(input), options)),
+        ["day4", "replay"] => day04::replay(&InputSource::for_day(4), options),
+        ["day4", "replay", input] => day04::replay(&InputSource::from_arg(input), options),
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+/// Name of the command named by the positional arguments and the options it accepts
+fn accepted_options(args: &[&str]) -> Option<(&'static str, &'static [&'static str])> {
+    match args {
+        ["run", ..] => Some(("run", &["--format"])),
+        ["bench", ..] => Some(("bench", &["--format"])),
+        ["verify", ..] => Some(("verify", &[])),
+        ["day1", "windows", ..] => Some(("day1 windows", &["--window"])),
+        ["day1", "stream", ..] => Some(("day1 stream", &["--window", "--format"])),
+        ["day1", "report", ..] => Some(("day1 report", &["--width"])),
+        ["day2", "dive", ..] => Some(("day2 dive", &["--model", "--accumulator"])),
+        ["day2", "trace", ..] => Some(("day2 trace", &["--model", "--accumulator", "--format"])),
+        ["day2", "svg", ..] => Some(("day2 svg", &["--model", "--accumulator"])),
+        ["day3", "report", ..] => Some(("day3 report", &["--tie", "--format"])),
+        ["day4", "play", ..] => Some(("day4 play", &["--rule", "--format"])),
+        ["day4", "replay", ..] => Some(("day4 replay", &["--rule", "--width"])),
+        _ => None,
+    }
+}
+
+/// Removes an option given as `--name value` or `--name=value` from the arguments
+fn take_option(args: &mut Vec<String>, name: &str) -> Result<Option<String>, String> {
+    let Some(i) = args
+        .iter()
+        .position(|arg| arg == name || arg.starts_with(&format!("{}=", name)))
+    else {
+        return Ok(None);
+    };
+    let arg = args.remove(i);
+    match arg.split_once('=') {
+        Some((_, value)) => Ok(Some(value.to_string())),
+        None if i < args.len() => Ok(Some(args.remove(i))),
+        None => Err(format!("missing value for `{}`", name)),
+    }
+}
+
+/// Solves every selected day and prints the answers with the time they took
+fn run(days: &[&Day], input: Option<InputSource>, format: Format) -> ExitCode {
+    let mut reports = vec![];
+    for day in days {
+        let source = input
+            .clone()
+            .unwrap_or_else(|| InputSource::for_day(day.number));
+        match solve(day, &source, 1) {
+            Some(report) => reports.push((day.number, report)),
+            None => return ExitCode::FAILURE,
+        }
+    }
+    match format {
+        Format::Table => print_table(
+            ["day", "part 1", "part 2", "parse", "time 1", "time 2"],
+            &reports
+                .into_iter()
+                .map(|(day, Report { parse, parts })| {
+                    let [part_1, part_2] = parts;
+                    [
+                        day.to_string(),
+                        part_1.answer,
+                        part_2.answer,
+                        human(parse),
+                        human(part_1.times[0]),
+                        human(part_2.times[0]),
+                    ]
+                })
+                .collect::<Vec<_>>(),
+        ),
+        Format::Csv | Format::Json => print_records(
+            format,
+            ["day", "part", "answer", "parse_ms", "solve_ms"],
+            &reports
+                .into_iter()
+                .flat_map(|(day, report)| {
+                    (1..).zip(report.parts).map(move |(part, result)| {
+                        [
+                            Field::Number(day.to_string()),
+                            Field::Number(part.to_string()),
+                            Field::Text(result.answer),
+                            Field::Number(millis(report.parse)),
+                            Field::Number(millis(result.times[0])),
+                        ]
+                    })
+                })
+                .collect::<Vec<_>>(),
+        ),
+    }
+    ExitCode::SUCCESS
+}
+
+/// Solves each part of every selected day several times and prints timing statistics
+fn bench(days: &[&Day], runs: usize, format: Format) -> ExitCode {
+    let mut rows = vec![];
+    for day in days {
+        let Some(report) = solve(day, &InputSource::for_day(day.number), runs) else {
+            return ExitCode::FAILURE;
+        };
+        for (part, result) in (1..).zip(report.parts) {
+            let stats = [result.min(), result.median(), result.mean()];
+            rows.push((day.number, part, stats));
+        }
+    }
+    match format {
+        Format::Table => print_table(
+            ["day", "part", "runs", "min", "median", "mean"],
+            &rows
+                .into_iter()
+                .map(|(day, part, [min, median, mean])| {
+                    [
+                        day.to_string(),
+                        part.to_string(),
+                        runs.to_string(),
+                        human(min),
+                        human(median),
+                        human(mean),
+                    ]
+                })
+                .collect::<Vec<_>>(),
+        ),
+        Format::Csv | Format::Json => print_records(
+            format,
+            ["day", "part", "runs", "min_ms", "median_ms", "mean_ms"],
+            &rows
+                .into_iter()
+                .map(|(day, part, [min, median, mean])| {
+                    [
+                        Field::Number(day.to_string()),
+                        Field::Number(part.to_string()),
+                        Field::Number(runs.to_string()),
+                        Field::Number(millis(min)),
+                        Field::Number(millis(median)),
+                        Field::Number(millis(mean)),
+                    ]
+                })
+                .collect::<Vec<_>>(),
+        ),
+    }
+    ExitCode::SUCCESS
+}
+
+/// Solves every selected day and compares the answers with the recorded ones
+fn verify(days: &[&Day], answers_path: &str) -> ExitCode {
+    let answers_source = InputSource::from_arg(answers_path);
+    let answers: Answers = match read(&answers_source)
+        .and_then(|content| parse(&answers_source, &content, |content| content.parse()))
+    {
+        Some(answers) => answers,
+        None => return ExitCode::FAILURE,
+    };
+    let mut rows = vec![];
+    let (mut failed, mut missing) = (0, 0);
+    for day in days {
+        let Some(report) = solve(day, &InputSource::for_day(day.number), 1) else {
+            failed += 2;
+            continue;
+        };
+        for (part, answer) in (1..).zip(report.answers()) {
+            let (status, expected) = match answers.check(day.number, part, &answer) {
+                Verdict::Pass => ("pass", String::new()),
+                Verdict::Fail { expected } => {
+                    failed += 1;
+                    ("FAIL", expected)
+                }
+                Verdict::Missing => {
+                    missing += 1;
+                    ("missing", String::new())
+                }
+            };
+            rows.push([
+                day.number.to_string(),
+                part.to_string(),
+                status.to_string(),
+                answer,
+                expected,
+            ]);
+        }
+    }
+    print_table(["day", "part", "status", "answer", "expected"], &rows);
+    println!(
+        "\n{} passed, {} failed, {} missing",
+        days.len() * 2 - failed - missing,
+        failed,
+        missing
+    );
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Reads the input of a day and solves each part `runs` times, reporting any error
+fn solve(day: &Day, source: &InputSource, runs: usize) -> Option<Report> {
+    let content = read(source)?;
+    parse(source, &content, |content| (day.run)(content, runs))
+}
+
+/// Reads a whole input, reporting the error if it cannot be read
+fn read(source: &InputSource) -> Option<String> {
+    source
+        .read()
+        .map_err(|err| eprintln!("error: cannot read {}: {}", source, err))
+        .ok()
+}
+
+/// Parses an input, reporting a parse error along with the line it points to
+fn parse<T>(
+    source: &InputSource,
+    content: &str,
+    parse: impl FnOnce(&str) -> Result<T, ParseError>,
+) -> Option<T> {
+    parse(content)
+        .map_err(|err| {
+            eprintln!("error: {}: {}", source, err);
+            if let Some(line) = content.lines().nth(err.line() - 1) {
+                let number = err.line().to_string();
+                eprintln!("{} | {}", number, line);
+                eprintln!(
+                    "{} | {}^",
+                    " ".repeat(number.len()),
+                    " ".repeat(err.column() - 1)
+                );
+            }
+        })
+        .ok()
+}
+
+/// Prints rows aligned under the given header, the first column to the right
+fn print_table<const N: usize>(header: [&str; N], rows: &[[String; N]]) {
+    let mut widths = header.map(|cell| cell.chars().count());
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let header = header.map(str::to_string);
+    for row in std::iter::once(&header).chain(rows) {
+        let mut line = String::new();
+        for (i, (cell, width)) in row.iter().zip(widths).enumerate() {
+            line += &match i {
+                0 => format!("{:>1$}", cell, width),
+                _ => format!("  {:<1$}", cell, width),
+            };
+        }
+        println!("{}", line.trim_end());
+    }
+}
+
+/// Prints records as CSV rows or as an array of JSON objects
+fn print_records<const N: usize>(format: Format, names: [&str; N], records: &[[Field; N]]) {
+    for line in render_records(format, names, records) {
+        println!("{}", line);
+    }
+}
+
+/// Value of a field missing from a record
+fn null(format: Format) -> String {
+    match format {
+        Format::Json => "null".to_string(),
+        _ => String::new(),
+    }
+}
+
+/// Lines of the records as CSV rows or as an array of JSON objects
+fn render_records<const N: usize>(
+    format: Format,
+    names: [&str; N],
+    records: &[[Field; N]],
+) -> Vec<String> {
+    let mut lines = vec![];
+    match format {
+        Format::Json => {
+            lines.push("[".to_string());
+            for (i, record) in records.iter().enumerate() {
+                let fields: Vec<String> = names
+                    .iter()
+                    .zip(record)
+                    .map(|(name, field)| match field {
+                        Field::Number(value) => format!("\"{}\": {}", name, value),
+                        Field::Text(value) => format!("\"{}\": {}", name, json_string(value)),
+                    })
+                    .collect();
+                let separator = if i + 1 < records.len() { "," } else { "" };
+                lines.push(format!("  {{{}}}{}", fields.join(", "), separator));
+            }
+            lines.push("]".to_string());
+        }
+        _ => {
+            lines.push(names.join(","));
+            for record in records {
+                let fields: Vec<String> = record
+                    .iter()
+                    .map(|field| match field {
+                        Field::Number(value) | Field::Text(value) => csv_field(value),
+                    })
+                    .collect();
+                lines.push(fields.join(","));
+            }
+        }
+    }
+    lines
+}
+
+/// Quotes a CSV field when it holds a separator, a quote or a line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Quotes a string as a JSON string literal
+fn json_string(value: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => quoted += "\\\"",
+            '\\' => quoted += "\\\\",
+            '\n' => quoted += "\\n",
+            c if c.is_control() => quoted += &format!("\\u{:04x}", c as u32),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Formats a duration with a unit suited to its magnitude
+fn human(duration: Duration) -> String {
+    match duration.as_nanos() {
+        nanos @ 0..=999 => format!("{}ns", nanos),
+        nanos @ 1_000..=999_999 => format!("{:.1}µs", nanos as f64 / 1e3),
+        nanos @ 1_000_000..=999_999_999 => format!("{:.1}ms", nanos as f64 / 1e6),
+        nanos => format!("{:.2}s", nanos as f64 / 1e9),
+    }
+}
+
+/// Formats a duration in milliseconds for machine-readable output
+fn millis(duration: Duration) -> String {
+    format!("{:.6}", duration.as_secs_f64() * 1e3)
+}
+
+/// Width of the terminal the output goes to, else the width exported by the
+/// shell, else the default one
+fn terminal_width() -> usize {
+    window_columns()
+        .or_else(|| {
+            std::env::var("COLUMNS")
+                .ok()
+                .and_then(|columns| columns.parse().ok())
+        })
+        .filter(|&columns| columns > 0)
+        .unwrap_or(DEFAULT_WIDTH)
+}
+
+// Number of columns of the terminal on the standard output, asked to the
+// terminal driver
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "openbsd",
+    target_os = "netbsd",
+    target_os = "dragonfly"
+))]
+fn window_columns() -> Option<usize> {
+    use std::os::raw::{c_int, c_ulong};
+
+    // Layout of `struct winsize` from <sys/ioctl.h>
+    #[repr(C)]
+    #[derive(Default)]
+    struct WindowSize {
+        rows: u16,
+        columns: u16,
+        x_pixels: u16,
+        y_pixels: u16,
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    const TIOCGWINSZ: c_ulong = 0x5413;
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    const TIOCGWINSZ: c_ulong = 0x4008_7468;
+    const STDOUT: c_int = 1;
+
+    extern "C" {
+        fn ioctl(fd: c_int, request: c_ulong, ...) -> c_int;
+    }
+
+    let mut size = WindowSize::default();
+    // SAFETY: TIOCGWINSZ only writes a `struct winsize` through the pointer,
+    // which points to a value of that layout living for the whole call
+    let status = unsafe { ioctl(STDOUT, TIOCGWINSZ, &mut size as *mut WindowSize) };
+    (status == 0).then_some(size.columns as usize)
+}
+
+// Terminals cannot be asked for their size on other systems
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "openbsd",
+    target_os = "netbsd",
+    target_os = "dragonfly"
+)))]
+fn window_columns() -> Option<usize> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> [[Field; 2]; 3] {
+        [
+            [
+                Field::Number("1".to_string()),
+                Field::Text("plain".to_string()),
+            ],
+            [
+                Field::Number("2".to_string()),
+                Field::Text("a, \"b\"".to_string()),
+            ],
+            [
+                Field::Number("3".to_string()),
+                Field::Text("two\nlines".to_string()),
+            ],
+        ]
+    }
+
+    fn take(command_line: &str) -> Result<Options, String> {
+        let mut args = command_line.split(' ').map(str::to_string).collect();
+        Options::take(&mut args)
+    }
+
+    #[test]
+    fn options_are_checked_against_the_command() {
+        assert_eq!(
+            take("run 1 --tie bogus"),
+            Err("`aoc run` does not accept `--tie`".to_string())
+        );
+        assert_eq!(
+            take("day3 report --tie bogus"),
+            Err("unknown tie-break policy `bogus`".to_string())
+        );
+        let options = take("day1 stream --window 7 --format=csv --window 2").unwrap();
+        assert_eq!((options.windows, options.format), (vec![7, 2], Format::Csv));
+    }
+
+    #[test]
+    fn json_strings_are_escaped() {
+        assert_eq!(json_string("plain"), "\"plain\"");
+        assert_eq!(json_string("say \"hi\""), "\"say \\\"hi\\\"\"");
+        assert_eq!(json_string("a\\b"), "\"a\\\\b\"");
+        assert_eq!(json_string("a\nb\tc\u{1b}"), "\"a\\nb\\u0009c\\u001b\"");
+    }
+
+    #[test]
+    fn json_records_are_separated_by_commas() {
+        assert_eq!(
+            render_records(Format::Json, ["day", "answer"], &records()),
+            [
+                "[",
+                "  {\"day\": 1, \"answer\": \"plain\"},",
+                "  {\"day\": 2, \"answer\": \"a, \\\"b\\\"\"},",
+                "  {\"day\": 3, \"answer\": \"two\\nlines\"}",
+                "]",
+            ]
+        );
+        assert_eq!(
+            render_records::<2>(Format::Json, ["day", "answer"], &[]),
+            ["[", "]"]
+        );
+    }
+
+    #[test]
+    fn csv_fields_are_quoted_when_needed() {
+        assert_eq!(
+            render_records(Format::Csv, ["day", "answer"], &records()),
+            [
+                "day,answer",
+                "1,plain",
+                "2,\"a, \"\"b\"\"\"",
+                "3,\"two\nlines\"",
+            ]
+        );
+    }
+}
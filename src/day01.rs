@@ -0,0 +1,325 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::io::{self, BufRead};
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::parse::{self, ParseError};
+use crate::solution::Solution;
+
+/// Puzzle input
+#[derive(Debug)]
+pub struct Input(pub Vec<i32>);
+
+// Conversion from string to puzzle input
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(content: &str) -> Result<Self, Self::Err> {
+        parse::lines(content, |line| parse::number(line, "a depth")).map(Input)
+    }
+}
+
+/// Number of times the sum of a sliding window of `window` depths increases
+///
+/// Two consecutive windows share all but their first and last depths, so the
+/// sum increases exactly when the depth entering the window is larger than
+/// the one leaving it.
+pub fn windowed_increases(depths: &[i32], window: usize) -> usize {
+    assert!(window > 0, "sliding windows must hold at least one depth");
+    depths
+        .iter()
+        .zip(depths.iter().skip(window))
+        .filter(|(leaving, entering)| entering > leaving)
+        .count()
+}
+
+/// Running count of depth increases over several sliding windows at once
+///
+/// Only the depths still inside the largest window are kept, so the counter
+/// can follow a sweep of any length.
+#[derive(Debug, Clone)]
+pub struct IncreaseCounter {
+    windows: Vec<usize>,
+    counts: Vec<usize>,
+    recent: VecDeque<i32>,
+}
+
+impl IncreaseCounter {
+    pub fn new(windows: &[usize]) -> Self {
+        assert!(
+            windows.iter().all(|&window| window > 0),
+            "sliding windows must hold at least one depth"
+        );
+        IncreaseCounter {
+            windows: windows.to_vec(),
+            counts: vec![0; windows.len()],
+            recent: VecDeque::new(),
+        }
+    }
+
+    /// Window sizes followed by the counter
+    pub fn windows(&self) -> &[usize] {
+        &self.windows
+    }
+
+    /// Increases counted so far for each window
+    pub fn counts(&self) -> &[usize] {
+        &self.counts
+    }
+
+    /// Takes the next depth into account and returns the updated counts
+    pub fn push(&mut self, depth: i32) -> &[usize] {
+        for (count, &window) in self.counts.iter_mut().zip(&self.windows) {
+            if window <= self.recent.len() && depth > self.recent[self.recent.len() - window] {
+                *count += 1;
+            }
+        }
+        self.recent.push_back(depth);
+        if self.recent.len() > self.windows.iter().copied().max().unwrap_or(0) {
+            self.recent.pop_front();
+        }
+        &self.counts
+    }
+}
+
+/// Error interrupting a stream of depth readings
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(err) => write!(f, "{}", err),
+            StreamError::Parse(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {}
+
+/// Reads depths line by line as they arrive and calls `emit` with every depth
+/// and the running counts, until the reader ends. Blank lines are skipped.
+pub fn stream(
+    reader: impl BufRead,
+    counter: &mut IncreaseCounter,
+    mut emit: impl FnMut(i32, &[usize]),
+) -> Result<(), StreamError> {
+    for (i, line) in reader.lines().enumerate() {
+        let line = line.map_err(StreamError::Io)?;
+        let reading = line.trim();
+        if reading.is_empty() {
+            continue;
+        }
+        let depth = parse::number(reading, "a depth")
+            .map_err(|err| StreamError::Parse(err.within(&line, reading).at_line(i + 1)))?;
+        emit(depth, counter.push(depth));
+    }
+    Ok(())
+}
+
+/// Summary statistics of a sonar sweep
+#[derive(Debug, Clone, PartialEq)]
+pub struct Profile {
+    pub min: i32,
+    pub max: i32,
+    pub mean: f64,
+    /// Indices of the first and last depths of the longest strictly increasing run
+    pub longest_run: RangeInclusive<usize>,
+    /// Largest change between two consecutive depths, if there are at least two
+    pub largest_jump: Option<Jump>,
+}
+
+/// Change of depth between a reading and the one before it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Jump {
+    /// Index of the reading the jump lands on
+    pub index: usize,
+    pub change: i64,
+}
+
+impl Profile {
+    /// Computes the statistics of a sweep, `None` when it holds no depth
+    pub fn new(depths: &[i32]) -> Option<Self> {
+        let min = *depths.iter().min()?;
+        let max = *depths.iter().max()?;
+        let mean = depths.iter().map(|&depth| depth as f64).sum::<f64>() / depths.len() as f64;
+        let mut longest_run = 0..=0;
+        let mut start = 0;
+        for i in 1..depths.len() {
+            if depths[i] <= depths[i - 1] {
+                start = i;
+            } else if i - start > longest_run.end() - longest_run.start() {
+                longest_run = start..=i;
+            }
+        }
+        let largest_jump = depths
+            .windows(2)
+            .enumerate()
+            .map(|(i, pair)| Jump {
+                index: i + 1,
+                change: pair[1] as i64 - pair[0] as i64,
+            })
+            .fold(None, |largest: Option<Jump>, jump| match largest {
+                Some(largest) if largest.change.abs() >= jump.change.abs() => Some(largest),
+                _ => Some(jump),
+            });
+        Some(Profile {
+            min,
+            max,
+            mean,
+            longest_run,
+            largest_jump,
+        })
+    }
+}
+
+/// Bars of a sparkline, from the shallowest to the deepest level
+const SPARKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Draws the depths as a sparkline at most `width` characters wide
+///
+/// When there are more depths than characters, each character shows the mean
+/// of a run of consecutive depths. Taller bars are deeper.
+pub fn sparkline(depths: &[i32], width: usize) -> String {
+    let buckets = depths.len().min(width);
+    let levels: Vec<f64> = (0..buckets)
+        .map(|i| {
+            let bucket = &depths[i * depths.len() / buckets..(i + 1) * depths.len() / buckets];
+            bucket.iter().map(|&depth| depth as f64).sum::<f64>() / bucket.len() as f64
+        })
+        .collect();
+    let min = levels.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = levels.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    levels
+        .iter()
+        .map(|level| {
+            let top = (SPARKS.len() - 1) as f64;
+            let spark = if max > min {
+                ((level - min) / (max - min) * top).round() as usize
+            } else {
+                0
+            };
+            SPARKS[spark]
+        })
+        .collect()
+}
+
+/// Day 1: Sonar Sweep
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        content.parse()
+    }
+
+    fn part_1(input: &Input) -> Self::Answer1 {
+        windowed_increases(&input.0, 1)
+    }
+
+    fn part_2(input: &Input) -> Self::Answer2 {
+        windowed_increases(&input.0, 3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+199
+200
+208
+210
+200
+207
+240
+269
+260
+263
+";
+
+    #[test]
+    fn part_1_sample() {
+        let input = Day01::parse(SAMPLE).unwrap();
+        assert_eq!(Day01::part_1(&input), 7);
+    }
+
+    #[test]
+    fn part_2_sample() {
+        let input = Day01::parse(SAMPLE).unwrap();
+        assert_eq!(Day01::part_2(&input), 5);
+    }
+
+    #[test]
+    fn windows_of_any_size() {
+        let input = Day01::parse(SAMPLE).unwrap();
+        let increases: Vec<usize> = (1..=5)
+            .map(|window| windowed_increases(&input.0, window))
+            .collect();
+        assert_eq!(increases, [7, 5, 5, 6, 5]);
+        assert_eq!(windowed_increases(&input.0, 10), 0);
+    }
+
+    #[test]
+    fn stream_counts_every_window() {
+        let mut counter = IncreaseCounter::new(&[1, 3]);
+        let mut emitted = vec![];
+        stream(SAMPLE.as_bytes(), &mut counter, |depth, counts| {
+            emitted.push((depth, counts.to_vec()))
+        })
+        .unwrap();
+        assert_eq!(emitted.len(), 10);
+        assert_eq!(emitted[3], (210, vec![3, 1]));
+        assert_eq!(counter.counts(), [7, 5]);
+    }
+
+    #[test]
+    fn stream_reports_the_bad_line() {
+        let mut counter = IncreaseCounter::new(&[1]);
+        match stream("199\n\n20x\n".as_bytes(), &mut counter, |_, _| {}) {
+            Err(StreamError::Parse(err)) => assert_eq!((err.line(), err.column()), (3, 1)),
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    fn profile_of_the_sample() {
+        let input = Day01::parse(SAMPLE).unwrap();
+        let profile = Profile::new(&input.0).unwrap();
+        assert_eq!((profile.min, profile.max), (199, 269));
+        assert_eq!(profile.mean, 225.6);
+        assert_eq!(profile.longest_run, 0..=3);
+        assert_eq!(
+            profile.largest_jump,
+            Some(Jump {
+                index: 6,
+                change: 33
+            })
+        );
+        assert_eq!(Profile::new(&[]), None);
+        let extremes = Profile::new(&[-2000000000, 2000000000]).unwrap();
+        assert_eq!(
+            extremes.largest_jump,
+            Some(Jump {
+                index: 1,
+                change: 4000000000
+            })
+        );
+    }
+
+    #[test]
+    fn sparkline_fits_the_width() {
+        assert_eq!(sparkline(&[1, 2, 3, 4, 5, 6, 7, 8], 80), "▁▂▃▄▅▆▇█");
+        assert_eq!(sparkline(&[1, 1, 8, 8], 2), "▁█");
+        assert_eq!(sparkline(&[5, 5, 5], 3), "▁▁▁");
+        assert_eq!(sparkline(&[], 3), "");
+    }
+}
@@ -0,0 +1,614 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::parse::{self, ParseError};
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    Up,
+    Down,
+    Forward,
+    Back,
+}
+
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "up" => Ok(Direction::Up),
+            "down" => Ok(Direction::Down),
+            "forward" => Ok(Direction::Forward),
+            "back" => Ok(Direction::Back),
+            _ => Err(ParseError::invalid(
+                input,
+                "`up`, `down`, `forward` or `back`",
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Direction::Up => "up",
+            Direction::Down => "down",
+            Direction::Forward => "forward",
+            Direction::Back => "back",
+        })
+    }
+}
+
+/// Command of the course: a direction, a distance and the line of the script
+/// it was written on, commands expanded from a `repeat` block or a macro
+/// keeping the line of the block or macro body they come from
+#[derive(Debug, Clone)]
+pub struct InputLine(pub Direction, pub i32, pub usize);
+
+impl FromStr for InputLine {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let split = parse::split_once(input, " ", "a direction followed by a distance")?;
+        let direction: Direction = split.0.parse()?;
+        let value: i32 =
+            parse::number(split.1, "a distance").map_err(|err| err.within(input, split.1))?;
+        Ok(InputLine(direction, value, 1))
+    }
+}
+
+impl fmt::Display for InputLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.0, self.1)
+    }
+}
+
+/// Largest number of commands a course script may expand to
+pub const MAX_COMMANDS: usize = 10_000_000;
+
+/// Commands of the course, with repetitions and macros expanded
+#[derive(Debug)]
+pub struct Input(pub Vec<InputLine>);
+
+// Conversion from string to puzzle input
+//
+// Besides one command per line, a course script may hold blank lines and `#`
+// comments, `repeat N { ... }` blocks and `macro name { ... }` definitions,
+// a macro being used afterwards by writing its name on a line of its own.
+// Blocks close with a `}` on a line of its own and may be nested. There is no
+// `turn` command: the course only has a horizontal axis and a depth, which
+// `forward`, `back`, `up` and `down` already cover.
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(content: &str) -> Result<Self, Self::Err> {
+        let mut lines = content.lines().enumerate().map(|(i, line)| (i + 1, line));
+        block(&mut lines, &mut HashMap::new(), None).map(Input)
+    }
+}
+
+// Parses statements up to the end of the input, or up to the `}` closing the
+// block opened on the given line, and expands them into plain commands
+fn block<'a>(
+    lines: &mut impl Iterator<Item = (usize, &'a str)>,
+    macros: &mut HashMap<&'a str, Vec<InputLine>>,
+    opening: Option<(usize, &'a str)>,
+) -> Result<Vec<InputLine>, ParseError> {
+    let mut commands = vec![];
+    while let Some((number, line)) = lines.next() {
+        let statement = line.split('#').next().unwrap_or_default().trim();
+        let at_line = |err: ParseError| err.within(line, statement).at_line(number);
+        if statement.is_empty() {
+            continue;
+        } else if statement == "}" {
+            return match opening {
+                Some(_) => Ok(commands),
+                None => Err(at_line(ParseError::invalid(statement, "a command"))),
+            };
+        } else if let Some(header) = statement.strip_prefix("repeat ") {
+            let at_header = |err: ParseError| at_line(err.within(statement, header));
+            let count = block_header(header).map_err(at_header)?;
+            let at_count = |err: ParseError| at_header(err.within(header, count));
+            let repetitions: usize =
+                parse::number(count, "a number of repetitions").map_err(at_count)?;
+            let body = block(lines, macros, Some((number, line)))?;
+            if expanded_length(&commands, &body, repetitions).is_none() {
+                return Err(at_count(ParseError::invalid(count, too_long())));
+            }
+            for _ in 0..repetitions {
+                commands.extend(body.iter().cloned());
+            }
+        } else if let Some(header) = statement.strip_prefix("macro ") {
+            let at_header = |err: ParseError| at_line(err.within(statement, header));
+            let name = block_header(header).map_err(at_header)?;
+            if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                let err = ParseError::invalid(name, "a macro name").within(header, name);
+                return Err(at_header(err));
+            }
+            let body = block(lines, macros, Some((number, line)))?;
+            macros.insert(name, body);
+        } else if !statement.contains(' ') {
+            let body = macros.get(statement).ok_or_else(|| {
+                at_line(ParseError::invalid(
+                    statement,
+                    "a command or a defined macro",
+                ))
+            })?;
+            if expanded_length(&commands, body, 1).is_none() {
+                return Err(at_line(ParseError::invalid(statement, too_long())));
+            }
+            commands.extend(body.iter().cloned());
+        } else {
+            let keyword = statement.split(' ').next().unwrap_or_default();
+            if keyword.parse::<Direction>().is_err() {
+                let expected = "`up`, `down`, `forward`, `back`, `repeat` or `macro`";
+                return Err(at_line(ParseError::invalid(keyword, expected)));
+            }
+            let InputLine(direction, value, _) = statement.parse().map_err(at_line)?;
+            commands.push(InputLine(direction, value, number));
+        }
+    }
+    match opening {
+        Some((number, line)) => {
+            Err(ParseError::missing(line.trim_end(), "a closing `}`").at_line(number))
+        }
+        None => Ok(commands),
+    }
+}
+
+// Length of the commands once the body is appended the given number of times,
+// `None` when it goes over the limit
+fn expanded_length(commands: &[InputLine], body: &[InputLine], times: usize) -> Option<usize> {
+    body.len()
+        .checked_mul(times)
+        .and_then(|length| length.checked_add(commands.len()))
+        .filter(|&length| length <= MAX_COMMANDS)
+}
+
+// What is expected from a block that would expand past the limit
+fn too_long() -> String {
+    format!(
+        "a course of at most {} commands once expanded",
+        MAX_COMMANDS
+    )
+}
+
+// Argument of a block header such as `repeat N {`, without the opening brace
+fn block_header(header: &str) -> Result<&str, ParseError> {
+    header
+        .strip_suffix('{')
+        .map(str::trim)
+        .ok_or_else(|| ParseError::missing(header, "an opening `{`"))
+}
+
+/// Integer type in which the position of the submarine is accumulated
+pub trait Accumulator:
+    'static + Copy + Default + Ord + fmt::Debug + fmt::Display + From<i32>
+{
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    /// Nearest floating point value, for drawing
+    fn as_f64(self) -> f64;
+}
+
+macro_rules! accumulator {
+    ($($int:ty),*) => {
+        $(
+            impl Accumulator for $int {
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$int>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$int>::checked_sub(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$int>::checked_mul(self, other)
+                }
+
+                fn as_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+accumulator!(i32, i64, i128);
+
+/// Position of the submarine, along with the aim used by some models
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct State<T> {
+    pub horizontal: T,
+    pub depth: T,
+    pub aim: T,
+}
+
+impl<T: Accumulator> State<T> {
+    /// Horizontal position multiplied by the depth, as the puzzle asks, `None`
+    /// when it does not fit the accumulator
+    pub fn product(&self) -> Option<T> {
+        self.horizontal.checked_mul(self.depth)
+    }
+}
+
+/// Meaning given to the commands steering the submarine
+pub trait Model<T> {
+    /// State reached by executing a command from the given state, `None` when
+    /// it does not fit the accumulator
+    fn apply(&self, state: State<T>, command: &InputLine) -> Option<State<T>>;
+}
+
+impl<T, M: Model<T> + ?Sized> Model<T> for &M {
+    fn apply(&self, state: State<T>, command: &InputLine) -> Option<State<T>> {
+        (**self).apply(state, command)
+    }
+}
+
+/// Commands of the first part: `up` and `down` change the depth directly,
+/// `back` undoes `forward`
+#[derive(Debug, Clone, Copy)]
+pub struct Simple;
+
+impl<T: Accumulator> Model<T> for Simple {
+    fn apply(&self, state: State<T>, InputLine(direction, x, _): &InputLine) -> Option<State<T>> {
+        let x = T::from(*x);
+        Some(match direction {
+            Direction::Up => State {
+                depth: state.depth.checked_sub(x)?,
+                ..state
+            },
+            Direction::Down => State {
+                depth: state.depth.checked_add(x)?,
+                ..state
+            },
+            Direction::Forward => State {
+                horizontal: state.horizontal.checked_add(x)?,
+                ..state
+            },
+            Direction::Back => State {
+                horizontal: state.horizontal.checked_sub(x)?,
+                ..state
+            },
+        })
+    }
+}
+
+/// Commands of the second part: `up` and `down` change the aim, and moving
+/// forward or back dives along it
+#[derive(Debug, Clone, Copy)]
+pub struct Aim;
+
+impl<T: Accumulator> Model<T> for Aim {
+    fn apply(&self, state: State<T>, InputLine(direction, x, _): &InputLine) -> Option<State<T>> {
+        let x = T::from(*x);
+        Some(match direction {
+            Direction::Up => State {
+                aim: state.aim.checked_sub(x)?,
+                ..state
+            },
+            Direction::Down => State {
+                aim: state.aim.checked_add(x)?,
+                ..state
+            },
+            Direction::Forward => State {
+                horizontal: state.horizontal.checked_add(x)?,
+                depth: state.depth.checked_add(state.aim.checked_mul(x)?)?,
+                ..state
+            },
+            Direction::Back => State {
+                horizontal: state.horizontal.checked_sub(x)?,
+                depth: state.depth.checked_sub(state.aim.checked_mul(x)?)?,
+                ..state
+            },
+        })
+    }
+}
+
+/// Every model known by name, in the order of the puzzle parts
+pub fn models<T: Accumulator>() -> [(&'static str, &'static dyn Model<T>); 2] {
+    [("simple", &Simple), ("aim", &Aim)]
+}
+
+/// Finds a model from its name
+pub fn model<T: Accumulator>(name: &str) -> Option<&'static dyn Model<T>> {
+    models()
+        .into_iter()
+        .find(|(model, _)| *model == name)
+        .map(|(_, model)| model)
+}
+
+/// Error raised when the position of the submarine no longer fits its accumulator
+#[derive(Debug, Clone)]
+pub struct Overflow {
+    pub command: InputLine,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "position overflows at line {} `{}`",
+            self.command.2, self.command
+        )
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// Submarine steered by commands under a given model
+#[derive(Debug, Clone)]
+pub struct Submarine<M, T> {
+    model: M,
+    state: State<T>,
+}
+
+impl<M: Model<T>, T: Accumulator> Submarine<M, T> {
+    /// Submarine at the surface, ready to follow commands
+    pub fn new(model: M) -> Self {
+        Submarine {
+            model,
+            state: State::default(),
+        }
+    }
+
+    pub fn state(&self) -> State<T> {
+        self.state
+    }
+
+    /// Executes a single command, leaving the state untouched when it overflows
+    pub fn execute(&mut self, command: &InputLine) -> Result<State<T>, Overflow> {
+        self.state = self
+            .model
+            .apply(self.state, command)
+            .ok_or_else(|| Overflow {
+                command: command.clone(),
+            })?;
+        Ok(self.state)
+    }
+
+    /// Executes every command in order, returning the final state
+    pub fn run<'a>(
+        &mut self,
+        commands: impl IntoIterator<Item = &'a InputLine>,
+    ) -> Result<State<T>, Overflow> {
+        for command in commands {
+            self.execute(command)?;
+        }
+        Ok(self.state)
+    }
+
+    /// Executes every command in order, returning the current state followed
+    /// by the state reached after each command
+    pub fn trace<'a>(
+        &mut self,
+        commands: impl IntoIterator<Item = &'a InputLine>,
+    ) -> Result<Vec<State<T>>, Overflow> {
+        let mut states = vec![self.state];
+        for command in commands {
+            states.push(self.execute(command)?);
+        }
+        Ok(states)
+    }
+}
+
+/// Draws the course followed through the given states as an SVG polyline
+///
+/// The horizontal position runs from left to right and the depth from top to
+/// bottom, each stretched to fill the picture.
+pub fn svg<T: Accumulator>(states: &[State<T>]) -> String {
+    let horizontal = states.iter().map(|state| state.horizontal);
+    let depth = states.iter().map(|state| state.depth);
+    let (left, right) = (horizontal.clone().min(), horizontal.max());
+    let (top, bottom) = (depth.clone().min(), depth.max());
+    let (left, top) = (left.unwrap_or_default(), top.unwrap_or_default());
+    let width = (right.unwrap_or_default().as_f64() - left.as_f64()).max(1.0);
+    let height = (bottom.unwrap_or_default().as_f64() - top.as_f64()).max(1.0);
+    let points: Vec<String> = states
+        .iter()
+        .map(|state| format!("{},{}", state.horizontal, state.depth))
+        .collect();
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"800\" height=\"400\" \
+         viewBox=\"{} {} {} {}\" preserveAspectRatio=\"none\">\n  \
+         <polyline points=\"{}\" fill=\"none\" stroke=\"black\" stroke-width=\"1\" \
+         vector-effect=\"non-scaling-stroke\"/>\n\
+         </svg>\n",
+        left,
+        top,
+        width,
+        height,
+        points.join(" ")
+    )
+}
+
+/// Day 2: Dive!
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Input;
+    type Answer1 = Answer<i128>;
+    type Answer2 = Answer<i128>;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        content.parse()
+    }
+
+    fn part_1(input: &Input) -> Self::Answer1 {
+        product(Simple, input)
+    }
+
+    fn part_2(input: &Input) -> Self::Answer2 {
+        product(Aim, input)
+    }
+}
+
+// Product of the final position and depth, unless either overflows
+fn product(model: impl Model<i128>, input: &Input) -> Answer<i128> {
+    Submarine::new(model)
+        .run(&input.0)
+        .map_err(|err| err.to_string())
+        .and_then(|state| state.product().ok_or_else(|| PRODUCT_OVERFLOW.to_string()))
+        .into()
+}
+
+/// Reason given when the product of the position and depth overflows
+pub const PRODUCT_OVERFLOW: &str = "the product of the position and depth overflows";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2
+";
+
+    #[test]
+    fn part_1_sample() {
+        let input = Day02::parse(SAMPLE).unwrap();
+        assert_eq!(Day02::part_1(&input), Answer::Solved(150));
+    }
+
+    #[test]
+    fn part_2_sample() {
+        let input = Day02::parse(SAMPLE).unwrap();
+        assert_eq!(Day02::part_2(&input), Answer::Solved(900));
+    }
+
+    #[test]
+    fn models_by_name() {
+        let input = Day02::parse(SAMPLE).unwrap();
+        let aim = model::<i64>("aim").unwrap();
+        let state = Submarine::new(aim).run(&input.0).unwrap();
+        assert_eq!(
+            state,
+            State {
+                horizontal: 15,
+                depth: 60,
+                aim: 10
+            }
+        );
+        assert!(model::<i64>("reverse").is_none());
+    }
+
+    const SCRIPT: &str = "\
+# the sample course, written with a macro and a repeat block
+macro dive {
+    forward 5
+    down 5 # steeper
+}
+dive
+repeat 2 {
+    forward 4
+}
+
+up 3
+down 8
+forward 2
+back 0
+";
+
+    #[test]
+    fn script_expands_to_the_sample() {
+        let script = Day02::parse(SCRIPT).unwrap();
+        let sample = Day02::parse(SAMPLE).unwrap();
+        assert_eq!(
+            Submarine::<_, i32>::new(Aim).run(&script.0).unwrap(),
+            Submarine::new(Aim).run(&sample.0).unwrap()
+        );
+        assert_eq!(script.0.len(), 8);
+    }
+
+    #[test]
+    fn script_errors_report_the_line() {
+        let err = Day02::parse("forward 1\n  sideways 2\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 3));
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: expected `up`, `down`, `forward`, `back`, `repeat` or `macro`, found `sideways`"
+        );
+        let err = "turn".parse::<Direction>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: expected `up`, `down`, `forward` or `back`, found `turn`"
+        );
+        let err = Day02::parse("repeat x {\n}\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (1, 8));
+        let err = Day02::parse("repeat   x {\n}\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (1, 10));
+        let err = Day02::parse("macro  a-b {\n}\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (1, 8));
+        let err = Day02::parse("forward 1\nrepeat 2 {\nup 1\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 11));
+        let err = Day02::parse("dive\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (1, 1));
+        let err = Day02::parse("}\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (1, 1));
+    }
+
+    #[test]
+    fn trace_and_svg() {
+        let input = Day02::parse(SAMPLE).unwrap();
+        let states = Submarine::<_, i32>::new(Simple).trace(&input.0).unwrap();
+        assert_eq!(states.len(), 7);
+        assert_eq!(states[0], State::default());
+        assert_eq!(states[6].product(), Some(150));
+        let svg = svg(&states);
+        assert!(svg.contains("viewBox=\"0 0 15 10\""));
+        assert!(svg.contains("points=\"0,0 5,0 5,5 13,5 13,2 13,10 15,10\""));
+    }
+
+    #[test]
+    fn overflow_reports_the_command() {
+        let input = Day02::parse("down 65536\nforward 32768\nforward 1\n").unwrap();
+        let err = Submarine::<_, i32>::new(Aim).run(&input.0).unwrap_err();
+        assert_eq!(err.command.2, 2);
+        assert_eq!(
+            err.to_string(),
+            "position overflows at line 2 `forward 32768`"
+        );
+        let script = "# dive deep\nrepeat 3 {\n  down 65536\n}\nforward 1\nforward 16384\n";
+        let scripted = Day02::parse(script).unwrap();
+        let err = Submarine::<_, i32>::new(Aim).run(&scripted.0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "position overflows at line 6 `forward 16384`"
+        );
+        let state = Submarine::<_, i64>::new(Aim).run(&input.0).unwrap();
+        assert_eq!(state.depth, 65536 * 32769);
+    }
+
+    #[test]
+    fn product_overflow_is_reported() {
+        let mut commands = vec![InputLine(Direction::Down, i32::MAX, 1); 4000];
+        commands.extend(vec![InputLine(Direction::Forward, i32::MAX, 2); 4000]);
+        let input = Input(commands);
+        let state = Submarine::<_, i128>::new(Aim).run(&input.0).unwrap();
+        assert_eq!(state.product(), None);
+        let answer = Day02::part_2(&input);
+        assert_eq!(answer, Answer::Unsolved(PRODUCT_OVERFLOW.to_string()));
+        assert_eq!(
+            answer.to_string(),
+            format!("no answer: {}", PRODUCT_OVERFLOW)
+        );
+    }
+
+    #[test]
+    fn script_expansion_is_bounded() {
+        let script = "repeat 100000 {\n  repeat  100000 {\n    forward 1\n  }\n}\n";
+        let err = Day02::parse(script).unwrap_err();
+        assert_eq!((err.line(), err.column()), (1, 8));
+        let script = "macro a {\nrepeat 10000000 {\nup 1\n}\n}\na\na\n";
+        let err = Day02::parse(script).unwrap_err();
+        assert_eq!((err.line(), err.column()), (7, 1));
+    }
+}
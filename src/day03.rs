@@ -0,0 +1,400 @@
+use std::cmp::Ordering;
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::parse::ParseError;
+use crate::solution::{Answer, Solution};
+
+/// Widest diagnostic numbers that can be packed
+pub const MAX_WIDTH: usize = 64;
+
+/// Diagnostic numbers packed in integers, the first bit of a line being the
+/// most significant one
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub width: usize,
+    pub numbers: Vec<u64>,
+}
+
+impl Input {
+    /// Mask of the bits used by the numbers
+    pub fn mask(&self) -> u64 {
+        u64::MAX
+            .checked_shr((MAX_WIDTH - self.width) as u32)
+            .unwrap_or(0)
+    }
+
+    /// Mask of the bit at the given column, counted from the left
+    pub fn bit(&self, column: usize) -> u64 {
+        1 << (self.width - 1 - column)
+    }
+}
+
+// Conversion from string to puzzle input
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(content: &str) -> Result<Self, Self::Err> {
+        let width = content.lines().next().map_or(0, str::len);
+        let mut numbers = vec![];
+        for (i, line) in content.lines().enumerate() {
+            numbers.push(number(line, width).map_err(|err| err.at_line(i + 1))?);
+        }
+        Ok(Input { width, numbers })
+    }
+}
+
+// Packs a line of bits, which must be `width` bits long
+fn number(line: &str, width: usize) -> Result<u64, ParseError> {
+    if line.len() != width {
+        return Err(ParseError::invalid(
+            line,
+            format!("a diagnostic of {} bits", width),
+        ));
+    }
+    if width > MAX_WIDTH {
+        return Err(ParseError::invalid(
+            line,
+            format!("a diagnostic of at most {} bits", MAX_WIDTH),
+        ));
+    }
+    line.chars()
+        .enumerate()
+        .try_fold(0, |number, (i, c)| match c {
+            '0' => Ok(number << 1),
+            '1' => Ok(number << 1 | 1),
+            _ => Err(ParseError::invalid(&c.to_string(), "a bit (`0` or `1`)").shift(i)),
+        })
+}
+
+/// Number of values having the given bit set
+pub fn ones(numbers: &[u64], bit: u64) -> usize {
+    numbers.iter().filter(|&&number| number & bit != 0).count()
+}
+
+/// How a column holding as many ones as zeros is settled
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TieBreak {
+    /// One is the most common bit, as the puzzle asks
+    #[default]
+    PreferOne,
+    /// Zero is the most common bit
+    PreferZero,
+    /// The computation fails
+    Error,
+}
+
+impl FromStr for TieBreak {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "one" => Ok(TieBreak::PreferOne),
+            "zero" => Ok(TieBreak::PreferZero),
+            "error" => Ok(TieBreak::Error),
+            _ => Err(ParseError::invalid(input, "`one`, `zero` or `error`")),
+        }
+    }
+}
+
+/// Error raised by the [`TieBreak::Error`] policy
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tie {
+    /// Index of the column, from the left
+    pub column: usize,
+    /// Number of ones, and of zeros
+    pub count: usize,
+}
+
+impl fmt::Display for Tie {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "column {} holds as many ones as zeros ({} each)",
+            self.column + 1,
+            self.count
+        )
+    }
+}
+
+impl std::error::Error for Tie {}
+
+/// Bits found in a column of the numbers considered
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Column {
+    /// Index of the column, from the left
+    pub index: usize,
+    pub ones: usize,
+    pub zeros: usize,
+}
+
+impl Column {
+    /// Whether one is the most common bit, ties being settled by the policy
+    pub fn majority(&self, policy: TieBreak) -> Result<bool, Tie> {
+        match (self.ones.cmp(&self.zeros), policy) {
+            (Ordering::Greater, _) => Ok(true),
+            (Ordering::Less, _) => Ok(false),
+            (Ordering::Equal, TieBreak::PreferOne) => Ok(true),
+            (Ordering::Equal, TieBreak::PreferZero) => Ok(false),
+            (Ordering::Equal, TieBreak::Error) => Err(Tie {
+                column: self.index,
+                count: self.ones,
+            }),
+        }
+    }
+}
+
+/// Bits found in every column of the report
+pub fn columns(input: &Input) -> Vec<Column> {
+    (0..input.width)
+        .map(|index| {
+            let ones = ones(&input.numbers, input.bit(index));
+            Column {
+                index,
+                ones,
+                zeros: input.numbers.len() - ones,
+            }
+        })
+        .collect()
+}
+
+/// Gamma and epsilon rates: the most and the least common bit of each column
+pub fn power(input: &Input, policy: TieBreak) -> Result<(u64, u64), Tie> {
+    let mut gamma = 0;
+    for column in columns(input) {
+        if column.majority(policy)? {
+            gamma |= input.bit(column.index);
+        }
+    }
+    Ok((gamma, !gamma & input.mask()))
+}
+
+/// Oxygen generator and CO2 scrubber ratings, keeping the numbers with the most
+/// and the least common bit of each column respectively
+pub fn life_support(trie: &Trie, policy: TieBreak) -> Result<Option<(u64, u64)>, Tie> {
+    let oxygen = trie.try_rating(|column| column.majority(policy))?;
+    let co2 = trie.try_rating(|column| column.majority(policy).map(|one| !one))?;
+    Ok(oxygen.zip(co2))
+}
+
+/// Binary trie of the diagnostic numbers, indexing them by their leading bits
+#[derive(Debug, Clone)]
+pub struct Trie {
+    width: usize,
+    nodes: Vec<Node>,
+}
+
+// Numbers sharing a prefix, and the nodes extending it with a zero and a one
+//
+// The root is never anyone's child, so index 0 marks a missing child.
+#[derive(Debug, Clone, Default)]
+struct Node {
+    count: usize,
+    children: [usize; 2],
+}
+
+impl Trie {
+    /// Indexes every number of the report
+    pub fn new(input: &Input) -> Self {
+        let mut trie = Trie {
+            width: input.width,
+            nodes: vec![Node::default()],
+        };
+        for &number in &input.numbers {
+            let mut node = 0;
+            trie.nodes[node].count += 1;
+            for column in 0..input.width {
+                let branch = (number & input.bit(column) != 0) as usize;
+                if trie.nodes[node].children[branch] == 0 {
+                    trie.nodes[node].children[branch] = trie.nodes.len();
+                    trie.nodes.push(Node::default());
+                }
+                node = trie.nodes[node].children[branch];
+                trie.nodes[node].count += 1;
+            }
+        }
+        trie
+    }
+
+    /// Number of indexed numbers
+    pub fn len(&self) -> usize {
+        self.nodes[0].count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Rating found by keeping, column after column, the numbers whose bit is
+    /// set when `keep_ones(ones, zeros)` holds and the others otherwise, until
+    /// a single number remains
+    ///
+    /// Only one node per column is visited, and the criterion is only asked
+    /// about columns where both bits are found. `None` is returned when the
+    /// trie is empty.
+    pub fn rating(&self, keep_ones: impl Fn(usize, usize) -> bool) -> Option<u64> {
+        let rating =
+            self.try_rating(|column| Ok::<_, Infallible>(keep_ones(column.ones, column.zeros)));
+        match rating {
+            Ok(rating) => rating,
+            Err(never) => match never {},
+        }
+    }
+
+    /// Rating found with a criterion that may fail, as [`Trie::rating`]
+    pub fn try_rating<E>(
+        &self,
+        keep_ones: impl Fn(Column) -> Result<bool, E>,
+    ) -> Result<Option<u64>, E> {
+        let mut node = &self.nodes[0];
+        if node.count == 0 {
+            return Ok(None);
+        }
+        let mut number = 0;
+        for index in 0..self.width {
+            let [zeros, ones] = node.children.map(|child| match child {
+                0 => 0,
+                child => self.nodes[child].count,
+            });
+            let branch = if ones == 0 || zeros == 0 {
+                (ones > 0) as usize
+            } else {
+                keep_ones(Column { index, ones, zeros })? as usize
+            };
+            node = &self.nodes[node.children[branch]];
+            number = number << 1 | branch as u64;
+        }
+        Ok(Some(number))
+    }
+}
+
+/// Day 3: Binary Diagnostic
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Input;
+    type Answer1 = Answer<u128>;
+    type Answer2 = Answer<u128>;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        content.parse()
+    }
+
+    fn part_1(input: &Input) -> Self::Answer1 {
+        if input.numbers.is_empty() {
+            return Answer::Unsolved(NO_NUMBERS.to_string());
+        }
+        power(input, TieBreak::PreferOne)
+            .map(|(gamma, epsilon)| gamma as u128 * epsilon as u128)
+            .into()
+    }
+
+    fn part_2(input: &Input) -> Self::Answer2 {
+        life_support(&Trie::new(input), TieBreak::PreferOne)
+            .map_err(|tie| tie.to_string())
+            .and_then(|ratings| ratings.ok_or_else(|| NO_NUMBERS.to_string()))
+            .map(|(oxygen, co2)| oxygen as u128 * co2 as u128)
+            .into()
+    }
+}
+
+/// Reason given when the report holds no diagnostic number
+pub const NO_NUMBERS: &str = "the report holds no diagnostic number";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010
+";
+
+    #[test]
+    fn part_1_sample() {
+        let input = Day03::parse(SAMPLE).unwrap();
+        assert_eq!(Day03::part_1(&input), Answer::Solved(198));
+    }
+
+    #[test]
+    fn part_2_sample() {
+        let input = Day03::parse(SAMPLE).unwrap();
+        assert_eq!(Day03::part_2(&input), Answer::Solved(230));
+    }
+
+    #[test]
+    fn empty_report_has_no_answer() {
+        let input = Day03::parse("").unwrap();
+        let unsolved = Answer::Unsolved(NO_NUMBERS.to_string());
+        assert_eq!(Day03::part_1(&input), unsolved);
+        assert_eq!(Day03::part_2(&input), unsolved);
+    }
+
+    #[test]
+    fn packed_numbers() {
+        let input = Day03::parse(SAMPLE).unwrap();
+        assert_eq!((input.width, input.numbers[0]), (5, 0b00100));
+        assert_eq!(input.mask(), 0b11111);
+        assert_eq!(power(&input, TieBreak::Error), Ok((22, 9)));
+        let wide = format!("{}\n{}\n", "1".repeat(64), "0".repeat(64));
+        let input = Day03::parse(&wide).unwrap();
+        assert_eq!(input.numbers, [u64::MAX, 0]);
+        assert_eq!(input.mask(), u64::MAX);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = Day03::parse("0101\n01x1\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 3));
+        let err = Day03::parse(&"1".repeat(65)).unwrap_err();
+        assert_eq!((err.line(), err.column()), (1, 1));
+    }
+
+    #[test]
+    fn trie_ratings() {
+        let input = Day03::parse(SAMPLE).unwrap();
+        let trie = Trie::new(&input);
+        assert_eq!(trie.len(), 12);
+        assert_eq!(trie.rating(|ones, zeros| ones >= zeros), Some(23));
+        assert_eq!(trie.rating(|ones, zeros| ones < zeros), Some(10));
+        assert_eq!(trie.rating(|ones, _| ones > 0), Some(0b11110));
+        assert_eq!(trie.rating(|_, _| false), Some(0b00010));
+        assert_eq!(
+            Trie::new(&Day03::parse("").unwrap()).rating(|_, _| true),
+            None
+        );
+    }
+
+    #[test]
+    fn tie_break_policies() {
+        let input = Day03::parse(SAMPLE).unwrap();
+        let first = Column {
+            index: 0,
+            ones: 7,
+            zeros: 5,
+        };
+        assert_eq!(columns(&input)[0], first);
+        let trie = Trie::new(&input);
+        assert_eq!(life_support(&trie, TieBreak::PreferOne), Ok(Some((23, 10))));
+        assert_eq!(
+            life_support(&trie, TieBreak::PreferZero),
+            Ok(Some((22, 15)))
+        );
+        let tie = Tie {
+            column: 4,
+            count: 1,
+        };
+        assert_eq!(life_support(&trie, TieBreak::Error), Err(tie));
+    }
+}
@@ -0,0 +1,712 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Index;
+use std::str::FromStr;
+
+use crate::parse::{self, ParseError};
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug, Clone)]
+pub struct Numbers(pub Vec<i32>);
+
+impl FromStr for Numbers {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        parse::separated(input, ",", |x| parse::number(x, "a drawn number")).map(Self)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BingoLine(pub Vec<(i32, bool)>);
+
+impl FromStr for BingoLine {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let line: Vec<(i32, bool)> = Result::from_iter(input.split_whitespace().map(|x| {
+            parse::number(x, "a board number")
+                .map(|r| (r, false))
+                .map_err(|err| err.within(input, x))
+        }))?;
+        if line.is_empty() {
+            return Err(ParseError::missing(input, "a board number"));
+        }
+        Ok(Self(line))
+    }
+}
+
+impl Index<usize> for BingoLine {
+    type Output = (i32, bool);
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.0.index(index)
+    }
+}
+
+/// Cells that must all be marked for a board to win
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WinRule {
+    /// Any complete row
+    Rows,
+    /// Any complete column
+    Columns,
+    /// Either diagonal of a square board
+    Diagonals,
+    /// The four corners
+    Corners,
+    /// Every cell of the board
+    Blackout,
+    /// Cells given by one bitmask per row from the top left corner, the
+    /// lowest bit standing for the leftmost column
+    Pattern(Vec<u64>),
+}
+
+/// Rules of the puzzle: a complete row or column wins
+pub const STANDARD_RULES: [WinRule; 2] = [WinRule::Rows, WinRule::Columns];
+
+impl WinRule {
+    /// Sets of cells of a board of the given size, the rule being met once
+    /// every cell of any of them is marked
+    pub fn lines(&self, height: usize, width: usize) -> Vec<Vec<(usize, usize)>> {
+        let cells = |i: usize| (0..width).map(move |j| (i, j));
+        let mut lines: Vec<Vec<(usize, usize)>> = match self {
+            WinRule::Rows => (0..height).map(|i| cells(i).collect()).collect(),
+            WinRule::Columns => (0..width)
+                .map(|j| (0..height).map(|i| (i, j)).collect())
+                .collect(),
+            WinRule::Diagonals if height == width => vec![
+                (0..height).map(|i| (i, i)).collect(),
+                (0..height).map(|i| (i, width - 1 - i)).collect(),
+            ],
+            WinRule::Diagonals => vec![],
+            WinRule::Corners if height > 0 && width > 0 => {
+                let mut corners = vec![
+                    (0, 0),
+                    (0, width - 1),
+                    (height - 1, 0),
+                    (height - 1, width - 1),
+                ];
+                corners.sort();
+                corners.dedup();
+                vec![corners]
+            }
+            WinRule::Corners => vec![],
+            WinRule::Blackout => vec![(0..height).flat_map(cells).collect()],
+            WinRule::Pattern(rows) => {
+                let line: Vec<(usize, usize)> = (0..rows.len())
+                    .flat_map(|i| (0..u64::BITS as usize).map(move |j| (i, j)))
+                    .filter(|&(i, j)| rows[i] >> j & 1 == 1)
+                    .collect();
+                let fits = line.iter().all(|&(i, j)| i < height && j < width);
+                if fits {
+                    vec![line]
+                } else {
+                    vec![]
+                }
+            }
+        };
+        lines.retain(|line| !line.is_empty());
+        lines
+    }
+
+    /// Whether the rule can be met on a board of the given size, which is not
+    /// the case of a pattern larger than the board or of the diagonals of a
+    /// board that is not square
+    pub fn applies_to(&self, height: usize, width: usize) -> bool {
+        !self.lines(height, width).is_empty()
+    }
+
+    /// Whether the board meets the rule
+    pub fn met_by(&self, bingo: &Bingo) -> bool {
+        self.lines(bingo.height(), bingo.width())
+            .iter()
+            .any(|line| line.iter().all(|&(i, j)| bingo[i][j].1))
+    }
+}
+
+// Conversion from a command line argument: the name of a rule, or a pattern
+// such as `10001/01010/00100/01010/10001` whose rows are separated by slashes
+impl FromStr for WinRule {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "rows" => Ok(WinRule::Rows),
+            "columns" => Ok(WinRule::Columns),
+            "diagonals" => Ok(WinRule::Diagonals),
+            "corners" => Ok(WinRule::Corners),
+            "blackout" => Ok(WinRule::Blackout),
+            _ => parse::separated(input, "/", |row| {
+                if row.is_empty() || row.len() > u64::BITS as usize {
+                    return Err(ParseError::invalid(row, "a pattern row of 1 to 64 cells"));
+                }
+                row.chars().enumerate().try_fold(0, |mask, (j, c)| match c {
+                    '0' => Ok(mask),
+                    '1' => Ok(mask | 1 << j),
+                    _ => Err(ParseError::invalid(
+                        &c.to_string(),
+                        "a rule name or a pattern of `0` and `1`",
+                    )
+                    .shift(j)),
+                })
+            })
+            .map(WinRule::Pattern),
+        }
+    }
+}
+
+impl fmt::Display for WinRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WinRule::Rows => write!(f, "rows"),
+            WinRule::Columns => write!(f, "columns"),
+            WinRule::Diagonals => write!(f, "diagonals"),
+            WinRule::Corners => write!(f, "corners"),
+            WinRule::Blackout => write!(f, "blackout"),
+            WinRule::Pattern(rows) => {
+                let width = rows
+                    .iter()
+                    .map(|row| (u64::BITS - row.leading_zeros()) as usize)
+                    .max()
+                    .unwrap_or(0)
+                    .max(1);
+                let rows: Vec<String> = rows
+                    .iter()
+                    .map(|row| {
+                        (0..width)
+                            .map(|j| if row >> j & 1 == 1 { '1' } else { '0' })
+                            .collect()
+                    })
+                    .collect();
+                write!(f, "{}", rows.join("/"))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Bingo(pub Vec<BingoLine>);
+
+impl Index<usize> for Bingo {
+    type Output = BingoLine;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.0.index(index)
+    }
+}
+
+impl Bingo {
+    /// Number of rows of the board
+    pub fn height(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Number of columns of the board
+    pub fn width(&self) -> usize {
+        self.0.first().map_or(0, |line| line.0.len())
+    }
+
+    pub fn mark(&mut self, number: i32) {
+        for line in &mut self.0 {
+            for entry in &mut line.0 {
+                entry.1 = entry.1 || entry.0 == number;
+            }
+        }
+    }
+
+    /// Whether every number of a row or of a column is marked
+    pub fn win(&self) -> bool {
+        self.wins(&STANDARD_RULES)
+    }
+
+    /// Whether the board meets any of the rules
+    pub fn wins(&self, rules: &[WinRule]) -> bool {
+        rules.iter().any(|rule| rule.met_by(self))
+    }
+
+    pub fn sum_unmarked(&self) -> i32 {
+        self.0
+            .iter()
+            .map(|line| {
+                line.0
+                    .iter()
+                    .fold(0, |acc, (x, c)| if *c { acc } else { acc + x })
+            })
+            .sum()
+    }
+}
+
+// Rows of right-aligned numbers, marked ones between brackets, or in reverse
+// video when the alternate flag `{:#}` is given
+impl fmt::Display for Bingo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = self
+            .0
+            .iter()
+            .flat_map(|line| &line.0)
+            .map(|(number, _)| number.to_string().len())
+            .max()
+            .unwrap_or(0);
+        for line in &self.0 {
+            for &(number, marked) in &line.0 {
+                match (marked, f.alternate()) {
+                    (false, _) => write!(f, " {:>1$} ", number, digits)?,
+                    (true, false) => write!(f, "[{:>1$}]", number, digits)?,
+                    (true, true) => write!(f, "\x1b[7m {:>1$} \x1b[0m", number, digits)?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct Input {
+    pub numbers: Numbers,
+    pub bingos: Vec<Bingo>,
+}
+
+// Conversion from string to puzzle input
+//
+// Boards are separated by one or more blank lines, and their size is given by
+// their rows: any number of them, all holding the same number of numbers.
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(content: &str) -> Result<Self, Self::Err> {
+        let mut lines = content.lines().enumerate().map(|(i, line)| (i + 1, line));
+        let numbers: Numbers = match lines.next() {
+            Some((_, line)) => line.parse()?,
+            None => return Err(ParseError::missing("", "the drawn numbers")),
+        };
+        let mut bingos = vec![];
+        let mut rows: Vec<BingoLine> = vec![];
+        for (number, line) in lines {
+            if line.trim().is_empty() {
+                if !rows.is_empty() {
+                    bingos.push(Bingo(std::mem::take(&mut rows)));
+                }
+                continue;
+            }
+            let row: BingoLine = line
+                .parse()
+                .map_err(|err: ParseError| err.at_line(number))?;
+            if let Some(first) = rows.first() {
+                if row.0.len() != first.0.len() {
+                    return Err(ParseError::invalid(
+                        line,
+                        format!("a board row of {} numbers", first.0.len()),
+                    )
+                    .at_line(number));
+                }
+            }
+            rows.push(row);
+        }
+        if !rows.is_empty() {
+            bingos.push(Bingo(rows));
+        }
+        Ok(Input { numbers, bingos })
+    }
+}
+
+/// Day 4: Giant Squid
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Input;
+    type Answer1 = Answer<i32>;
+    type Answer2 = Answer<i32>;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        content.parse()
+    }
+
+    fn part_1(input: &Input) -> Self::Answer1 {
+        let ranking = simulate(input, &STANDARD_RULES);
+        ranking.first().map(|win| win.score).ok_or(NO_WINNER).into()
+    }
+
+    fn part_2(input: &Input) -> Self::Answer2 {
+        let ranking = simulate(input, &STANDARD_RULES);
+        ranking.last().map(|win| win.score).ok_or(NO_WINNER).into()
+    }
+}
+
+/// Reason given when no board wins with the drawn numbers
+pub const NO_WINNER: &str = "no board ever wins";
+
+/// Board winning a game, and the draw it won on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Win {
+    pub board_index: usize,
+    pub draw_index: usize,
+    pub winning_number: i32,
+    pub unmarked_sum: i32,
+    pub score: i32,
+}
+
+/// Outcome of a game: every board that wins, in the order it wins, and every
+/// board that never does
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ranking {
+    pub wins: Vec<Win>,
+    pub losers: Vec<usize>,
+}
+
+impl Ranking {
+    /// First board to win
+    pub fn first(&self) -> Option<&Win> {
+        self.wins.first()
+    }
+
+    /// Last board to win
+    pub fn last(&self) -> Option<&Win> {
+        self.wins.last()
+    }
+}
+
+/// Bingo game where every drawn number only visits the cells holding it
+///
+/// The cells of every board are indexed by their number, and each board
+/// counts the marked cells of every line of the rules, so that marking a cell
+/// and telling whether its board wins take a time independent of the size of
+/// the boards.
+#[derive(Debug, Clone)]
+pub struct Game {
+    bingos: Vec<Bingo>,
+    cells: HashMap<i32, Vec<(usize, usize, usize)>>,
+    layouts: Vec<Layout>,
+    boards: Vec<BoardState>,
+}
+
+// Lines of the rules on boards of a given size
+#[derive(Debug, Clone)]
+struct Layout {
+    /// Lines going through each cell, row after row
+    lines_of: Vec<Vec<usize>>,
+    lengths: Vec<usize>,
+}
+
+// Progress of a board through the game
+#[derive(Debug, Clone)]
+struct BoardState {
+    layout: usize,
+    hits: Vec<usize>,
+    unmarked_sum: i32,
+    won: bool,
+}
+
+impl Game {
+    /// Indexes the boards for a game under the given rules
+    pub fn new(bingos: &[Bingo], rules: &[WinRule]) -> Self {
+        let mut cells: HashMap<i32, Vec<(usize, usize, usize)>> = HashMap::new();
+        let mut shapes = HashMap::new();
+        let mut layouts = vec![];
+        let mut boards = vec![];
+        for (board, bingo) in bingos.iter().enumerate() {
+            let (height, width) = (bingo.height(), bingo.width());
+            let layout = *shapes.entry((height, width)).or_insert_with(|| {
+                layouts.push(Layout::new(height, width, rules));
+                layouts.len() - 1
+            });
+            for (i, line) in bingo.0.iter().enumerate() {
+                for (j, &(number, _)) in line.0.iter().enumerate() {
+                    cells.entry(number).or_default().push((board, i, j));
+                }
+            }
+            boards.push(BoardState {
+                layout,
+                hits: vec![0; layouts[layout].lengths.len()],
+                unmarked_sum: 0,
+                won: false,
+            });
+        }
+        let mut game = Game {
+            bingos: bingos.to_vec(),
+            cells,
+            layouts,
+            boards,
+        };
+        // Cells marked beforehand are marked again through the counters
+        let mut marked = vec![];
+        for (board, bingo) in game.bingos.iter_mut().enumerate() {
+            for (i, line) in bingo.0.iter_mut().enumerate() {
+                for (j, cell) in line.0.iter_mut().enumerate() {
+                    if std::mem::take(&mut cell.1) {
+                        marked.push((board, i, j));
+                    }
+                }
+            }
+            game.boards[board].unmarked_sum = bingo.sum_unmarked();
+        }
+        for (board, i, j) in marked {
+            if game.hit(board, i, j) {
+                game.boards[board].won = true;
+            }
+        }
+        game
+    }
+
+    /// Boards in their current state
+    pub fn bingos(&self) -> &[Bingo] {
+        &self.bingos
+    }
+
+    /// Whether the board has already won
+    pub fn has_won(&self, board: usize) -> bool {
+        self.boards[board].won
+    }
+
+    /// Marks a number on every board, returning the boards that win with it
+    /// in increasing order
+    ///
+    /// Boards that already won keep being marked, so that they show every
+    /// number drawn, but they do not win again.
+    pub fn draw(&mut self, number: i32) -> Vec<usize> {
+        let mut winners = vec![];
+        // Once drawn, the cells of a number are marked for good
+        let cells = self.cells.remove(&number).unwrap_or_default();
+        for (board, i, j) in cells {
+            if self.hit(board, i, j) && !self.boards[board].won {
+                self.boards[board].won = true;
+                winners.push(board);
+            }
+        }
+        winners.sort_unstable();
+        winners.dedup();
+        winners
+    }
+
+    /// Sum of the numbers of the board that are not marked yet
+    pub fn unmarked_sum(&self, board: usize) -> i32 {
+        self.boards[board].unmarked_sum
+    }
+
+    // Marks a cell, returning whether it completes one of the lines of its board
+    fn hit(&mut self, board: usize, i: usize, j: usize) -> bool {
+        let bingo = &mut self.bingos[board];
+        let width = bingo.width();
+        let cell = &mut bingo.0[i].0[j];
+        if cell.1 {
+            return false;
+        }
+        cell.1 = true;
+        let state = &mut self.boards[board];
+        state.unmarked_sum -= cell.0;
+        let layout = &self.layouts[state.layout];
+        let mut complete = false;
+        for &line in &layout.lines_of[i * width + j] {
+            state.hits[line] += 1;
+            complete |= state.hits[line] == layout.lengths[line];
+        }
+        complete
+    }
+}
+
+impl Layout {
+    fn new(height: usize, width: usize, rules: &[WinRule]) -> Self {
+        let mut lines_of = vec![vec![]; height * width];
+        let mut lengths = vec![];
+        for line in rules.iter().flat_map(|rule| rule.lines(height, width)) {
+            for &(i, j) in &line {
+                lines_of[i * width + j].push(lengths.len());
+            }
+            lengths.push(line.len());
+        }
+        Layout { lines_of, lengths }
+    }
+}
+
+/// Draws every number and records when each board wins under the given rules
+///
+/// Boards winning on the same draw are ranked by their index.
+pub fn simulate(input: &Input, rules: &[WinRule]) -> Ranking {
+    let mut game = Game::new(&input.bingos, rules);
+    let mut wins = vec![];
+    for (draw_index, &number) in input.numbers.0.iter().enumerate() {
+        for board_index in game.draw(number) {
+            let unmarked_sum = game.unmarked_sum(board_index);
+            wins.push(Win {
+                board_index,
+                draw_index,
+                winning_number: number,
+                unmarked_sum,
+                score: unmarked_sum * number,
+            });
+        }
+    }
+    let losers = (0..input.bingos.len())
+        .filter(|&board| !game.has_won(board))
+        .collect();
+    Ranking { wins, losers }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7
+";
+
+    #[test]
+    fn part_1_sample() {
+        let input = Day04::parse(SAMPLE).unwrap();
+        assert_eq!(Day04::part_1(&input), Answer::Solved(4512));
+    }
+
+    #[test]
+    fn part_2_sample() {
+        let input = Day04::parse(SAMPLE).unwrap();
+        assert_eq!(Day04::part_2(&input), Answer::Solved(1924));
+    }
+
+    #[test]
+    fn boards_of_any_size() {
+        let input = Day04::parse("1,2,3,4\n\n\n1 2 3\n4 5 6\n\n  \n7\n8\n").unwrap();
+        let sizes: Vec<(usize, usize)> = input
+            .bingos
+            .iter()
+            .map(|bingo| (bingo.height(), bingo.width()))
+            .collect();
+        assert_eq!(sizes, [(2, 3), (2, 1)]);
+        assert_eq!(Day04::part_1(&input), Answer::Solved(15 * 3));
+    }
+
+    #[test]
+    fn parts_without_a_winner_have_no_answer() {
+        let input = Day04::parse("1,2\n\n3 4\n5 6\n").unwrap();
+        let unsolved = Answer::Unsolved(NO_WINNER.to_string());
+        assert_eq!(Day04::part_1(&input), unsolved);
+        assert_eq!(Day04::part_2(&input), unsolved);
+    }
+
+    #[test]
+    fn rows_of_a_board_have_the_same_width() {
+        let err = Day04::parse("1,2\n\n1 2 3\n4 5\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (4, 1));
+        let err = Day04::parse("1,2\n\n1 2 3\n4 x 6\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (4, 3));
+    }
+
+    #[test]
+    fn win_rules() {
+        let input = Day04::parse(SAMPLE).unwrap();
+        let mut bingo = input.bingos[0].clone();
+        for number in [22, 2, 14, 18] {
+            bingo.mark(number);
+        }
+        assert!(!bingo.wins(&[WinRule::Diagonals]));
+        bingo.mark(19);
+        assert!(bingo.wins(&[WinRule::Diagonals]));
+        assert!(!bingo.win());
+        for number in [0, 1] {
+            bingo.mark(number);
+        }
+        assert!(bingo.wins(&[WinRule::Corners]));
+        let cross: WinRule = "10001/01010/00100/01010/10001".parse().unwrap();
+        assert!(!bingo.wins(&[cross.clone(), WinRule::Blackout]));
+        for number in [11, 4, 16, 10, 15, 12] {
+            bingo.mark(number);
+        }
+        assert!(bingo.wins(&[cross]));
+        let err = "10001/10x".parse::<WinRule>().unwrap_err();
+        assert_eq!(err.column(), 9);
+    }
+
+    #[test]
+    fn rules_too_large_for_a_board() {
+        let square: WinRule = "111/111".parse().unwrap();
+        assert!(square.applies_to(3, 3));
+        assert!(!square.applies_to(2, 2));
+        assert!(WinRule::Diagonals.applies_to(2, 2));
+        assert!(!WinRule::Diagonals.applies_to(2, 3));
+        assert!(WinRule::Corners.applies_to(1, 1));
+        for (rule, shown) in [
+            ("rows", "rows"),
+            ("diagonals", "diagonals"),
+            ("01/0/110", "01/00/11"),
+        ] {
+            assert_eq!(rule.parse::<WinRule>().unwrap().to_string(), shown);
+        }
+    }
+
+    #[test]
+    fn ranking_of_every_board() {
+        let input = Day04::parse(SAMPLE).unwrap();
+        let ranking = simulate(&input, &STANDARD_RULES);
+        let order: Vec<(usize, usize, i32)> = ranking
+            .wins
+            .iter()
+            .map(|win| (win.board_index, win.draw_index, win.winning_number))
+            .collect();
+        assert_eq!(order, [(2, 11, 24), (0, 13, 16), (1, 14, 13)]);
+        assert_eq!(ranking.last().unwrap().unmarked_sum, 148);
+        assert!(ranking.losers.is_empty());
+        let ranking = simulate(&input, &[WinRule::Pattern(vec![1 << 5])]);
+        assert!(ranking.wins.is_empty());
+        assert_eq!(ranking.losers, [0, 1, 2]);
+    }
+
+    #[test]
+    fn game_agrees_with_scanning_the_boards() {
+        let input = Day04::parse(SAMPLE).unwrap();
+        let rules = [
+            WinRule::Diagonals,
+            WinRule::Corners,
+            "11/01".parse().unwrap(),
+        ];
+        let mut game = Game::new(&input.bingos, &rules);
+        let mut bingos = input.bingos.clone();
+        for &number in &input.numbers.0 {
+            let winners = game.draw(number);
+            let mut expected = vec![];
+            for (board, bingo) in bingos.iter_mut().enumerate() {
+                if !bingo.wins(&rules) {
+                    bingo.mark(number);
+                    if bingo.wins(&rules) {
+                        expected.push(board);
+                    }
+                }
+            }
+            assert_eq!(winners, expected);
+            for (board, bingo) in bingos.iter().enumerate() {
+                if !bingo.wins(&rules) || winners.contains(&board) {
+                    assert_eq!(game.unmarked_sum(board), bingo.sum_unmarked());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn display_highlights_marked_cells() {
+        let input = Day04::parse("5,10\n\n5 10\n100 7\n").unwrap();
+        let mut bingo = input.bingos[0].clone();
+        bingo.mark(10);
+        assert_eq!(bingo.to_string(), "   5 [ 10]\n 100    7 \n");
+        assert_eq!(
+            format!("{:#}", bingo).lines().next(),
+            Some("   5 \x1b[7m  10 \x1b[0m")
+        );
+    }
+}
@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+use std::ops::Index;
+use std::str::FromStr;
+
+use crate::parse::{self, ParseError};
+use crate::solution::Solution;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl FromStr for Point {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (x, y) = parse::split_once(input, ",", "a point `x,y`")?;
+        Ok(Self {
+            x: parse::number(x, "a coordinate")?,
+            y: parse::number(y, "a coordinate").map_err(|err| err.within(input, y))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Line {
+    pub from: Point,
+    pub to: Point,
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (from, to) = parse::split_once(input, " -> ", "a line `x1,y1 -> x2,y2`")?;
+        Ok(Self {
+            from: from.parse()?,
+            to: to
+                .parse()
+                .map_err(|err: ParseError| err.within(input, to))?,
+        })
+    }
+}
+
+impl Line {
+    pub fn is_inline(&self) -> bool {
+        self.from.x == self.to.x || self.from.y == self.to.y
+    }
+}
+
+#[derive(Debug)]
+pub struct Lines(pub Vec<Line>);
+
+impl Index<usize> for Lines {
+    type Output = Line;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.0.index(index)
+    }
+}
+
+// Conversion from string to puzzle input
+impl FromStr for Lines {
+    type Err = ParseError;
+
+    fn from_str(content: &str) -> Result<Self, Self::Err> {
+        parse::lines(content, str::parse).map(Self)
+    }
+}
+
+/// Day 5: Hydrothermal Venture
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Lines;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        content.parse()
+    }
+
+    fn part_1(input: &Lines) -> Self::Answer1 {
+        input
+            .0
+            .iter()
+            .filter(|line| line.is_inline())
+            .flat_map(|line| {
+                let Line { from, to } = line;
+                let mut v = vec![];
+                for i in from.x.min(to.x)..from.x.max(to.x) + 1 {
+                    for j in from.y.min(to.y)..from.y.max(to.y) + 1 {
+                        v.push(Point { x: i, y: j })
+                    }
+                }
+                v
+            })
+            .fold(HashMap::new(), |mut acc, x| {
+                match acc.get_mut(&x) {
+                    Some(count) => *count += 1,
+                    _ => {
+                        acc.insert(x, 1);
+                    }
+                }
+                acc
+            })
+            .iter()
+            .filter(|(_, nbr)| **nbr >= 2)
+            .count()
+    }
+
+    fn part_2(input: &Lines) -> Self::Answer2 {
+        input
+            .0
+            .iter()
+            .flat_map(|line| {
+                let Line { from, to } = line;
+                let mut v = vec![];
+                if !line.is_inline() {
+                    let slopx = if from.x > to.x { -1 } else { 1 };
+                    let slopy = if from.y > to.y { -1 } else { 1 };
+                    let size = (to.x - from.x).abs();
+                    for i in 0..=size {
+                        v.push(Point {
+                            x: from.x + (i * slopx),
+                            y: from.y + (i * slopy),
+                        });
+                    }
+                } else {
+                    for i in from.x.min(to.x)..=from.x.max(to.x) {
+                        for j in from.y.min(to.y)..=from.y.max(to.y) {
+                            v.push(Point { x: i, y: j })
+                        }
+                    }
+                }
+                v
+            })
+            .fold(HashMap::new(), |mut acc, x| {
+                match acc.get_mut(&x) {
+                    Some(count) => *count += 1,
+                    _ => {
+                        acc.insert(x, 1);
+                    }
+                }
+                acc
+            })
+            .iter()
+            .filter(|(_, nbr)| **nbr >= 2)
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2
+";
+
+    #[test]
+    fn part_1_sample() {
+        let input = Day05::parse(SAMPLE).unwrap();
+        assert_eq!(Day05::part_1(&input), 5);
+    }
+
+    #[test]
+    fn part_2_sample() {
+        let input = Day05::parse(SAMPLE).unwrap();
+        assert_eq!(Day05::part_2(&input), 12);
+    }
+}
@@ -0,0 +1,91 @@
+use std::ops::Index;
+use std::str::FromStr;
+
+use crate::parse::{self, ParseError};
+use crate::solution::Solution;
+
+#[derive(Debug)]
+pub struct Input(pub Vec<i32>);
+
+impl Index<usize> for Input {
+    type Output = i32;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.0.index(index)
+    }
+}
+
+// Conversion from string to puzzle input
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(content: &str) -> Result<Self, Self::Err> {
+        let list = content.trim();
+        parse::separated(list, ",", |num| match parse::number(num, "a timer")? {
+            timer @ 0..=6 => Ok(timer),
+            _ => Err(ParseError::invalid(num, "a timer between 0 and 6")),
+        })
+        .map_err(|err| err.within(content, list))
+        .map(Self)
+    }
+}
+
+fn compute(start: &[i32], days: i32) -> i64 {
+    let mut adults = vec![0; 7];
+    let mut babies = [0; 2];
+    for init in start.iter() {
+        let i: usize = (*init).try_into().unwrap();
+        adults[i] += 1
+    }
+    for _ in 0..days {
+        let head = adults[0];
+        adults = adults[1..].to_vec();
+        adults.push(head);
+        adults[6] += babies[0];
+        babies[0] = babies[1];
+        babies[1] = head;
+    }
+    adults.iter().sum::<i64>() + babies.iter().sum::<i64>()
+}
+
+/// Day 6: Lanternfish
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Input;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        content.parse()
+    }
+
+    fn part_1(input: &Input) -> Self::Answer1 {
+        compute(&input.0, 80)
+    }
+
+    fn part_2(input: &Input) -> Self::Answer2 {
+        compute(&input.0, 256)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+3,4,3,1,2
+";
+
+    #[test]
+    fn part_1_sample() {
+        let input = Day06::parse(SAMPLE).unwrap();
+        assert_eq!(Day06::part_1(&input), 5934);
+    }
+
+    #[test]
+    fn part_2_sample() {
+        let input = Day06::parse(SAMPLE).unwrap();
+        assert_eq!(Day06::part_2(&input), 26984457539);
+    }
+}
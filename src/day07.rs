@@ -0,0 +1,94 @@
+use std::ops::Index;
+use std::str::FromStr;
+
+use crate::parse::{self, ParseError};
+use crate::solution::Solution;
+
+#[derive(Debug)]
+pub struct Input(pub Vec<i32>);
+
+impl Index<usize> for Input {
+    type Output = i32;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.0.index(index)
+    }
+}
+
+// Conversion from string to puzzle input
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(content: &str) -> Result<Self, Self::Err> {
+        let list = content.trim();
+        parse::separated(list, ",", |num| parse::number(num, "a position"))
+            .map_err(|err| err.within(content, list))
+            .map(Self)
+    }
+}
+
+/// Day 7: The Treachery of Whales
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Input;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        content.parse()
+    }
+
+    fn part_1(input: &Input) -> Self::Answer1 {
+        cheapest(input, |distance| distance)
+    }
+
+    fn part_2(input: &Input) -> Self::Answer2 {
+        cheapest(input, sum)
+    }
+}
+
+// Least fuel spent aligning the crabs on a position between the outermost ones,
+// moving a crab by a distance costing `cost(distance)`
+fn cheapest(input: &Input, cost: impl Fn(i32) -> i32) -> i32 {
+    let min = input.0.iter().copied().min().unwrap_or_default();
+    let max = input.0.iter().copied().max().unwrap_or_default();
+    (min..=max)
+        .map(|i| input.0.iter().fold(0, |acc, x| cost((x - i).abs()) + acc))
+        .min()
+        .unwrap_or_default()
+}
+
+fn sum(n: i32) -> i32 {
+    (n * (n + 1)) / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+16,1,2,0,4,2,7,1,2,14
+";
+
+    #[test]
+    fn part_1_sample() {
+        let input = Day07::parse(SAMPLE).unwrap();
+        assert_eq!(Day07::part_1(&input), 37);
+    }
+
+    #[test]
+    fn part_2_sample() {
+        let input = Day07::parse(SAMPLE).unwrap();
+        assert_eq!(Day07::part_2(&input), 168);
+    }
+
+    #[test]
+    fn crabs_already_aligned() {
+        for content in ["0", "5", "-3,-3"] {
+            let input = Day07::parse(content).unwrap();
+            assert_eq!(Day07::part_1(&input), 0);
+            assert_eq!(Day07::part_2(&input), 0);
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use std::collections::HashSet;
+use std::ops::Index;
+use std::str::FromStr;
+
+use crate::parse::{self, ParseError};
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug)]
+pub struct Input(pub Vec<(Vec<String>, Vec<String>)>);
+
+impl Index<usize> for Input {
+    type Output = (Vec<String>, Vec<String>);
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.0.index(index)
+    }
+}
+
+// Conversion from string to puzzle input
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(content: &str) -> Result<Self, Self::Err> {
+        parse::lines(content, |line| {
+            let (pattern, output) = parse::split_once(line, " | ", "patterns `|` output")?;
+            Ok((
+                digits(pattern, 10).map_err(|err| err.within(line, pattern))?,
+                digits(output, 4).map_err(|err| err.within(line, output))?,
+            ))
+        })
+        .map(Self)
+    }
+}
+
+// Parses a space separated list of `count` digits written with segments `a` to `g`
+fn digits(input: &str, count: usize) -> Result<Vec<String>, ParseError> {
+    let digits = parse::separated(input, " ", |digit| {
+        if !digit.is_empty() && digit.chars().all(|c| ('a'..='g').contains(&c)) {
+            Ok(digit.to_string())
+        } else {
+            Err(ParseError::invalid(digit, "segments `a` to `g`"))
+        }
+    })?;
+    if digits.len() != count {
+        return Err(ParseError::invalid(input, format!("{} digits", count)));
+    }
+    Ok(digits)
+}
+
+/// Day 8: Seven Segment Search
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Input;
+    type Answer1 = i32;
+    type Answer2 = Answer<i32>;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        content.parse()
+    }
+
+    fn part_1(input: &Input) -> Self::Answer1 {
+        let lens = HashSet::from([2, 3, 4, 7]);
+        input
+            .0
+            .iter()
+            .map(|(_, output)| output.iter().filter(|x| lens.contains(&x.len())).count() as i32)
+            .sum::<i32>()
+    }
+
+    fn part_2(input: &Input) -> Self::Answer2 {
+        input
+            .0
+            .iter()
+            .enumerate()
+            .map(|(i, (pattern, output))| {
+                decode(pattern, output).ok_or_else(|| {
+                    format!(
+                        "the patterns of display {} do not form the ten digits",
+                        i + 1
+                    )
+                })
+            })
+            .sum::<Result<i32, String>>()
+            .into()
+    }
+}
+
+/// Resolves the wiring of a display from its ten patterns and reads the output value,
+/// `None` when the patterns do not form the ten digits
+pub fn decode(pattern: &[String], output: &[String]) -> Option<i32> {
+    let mut pattern = pattern.to_vec();
+    pattern.sort_by_key(|d1| d1.len());
+    let lengths: Vec<usize> = pattern.iter().map(String::len).collect();
+    if lengths != [2, 3, 4, 5, 5, 5, 6, 6, 6, 7] {
+        return None;
+    }
+    let one = to_set(pattern[0].clone());
+    let seven = to_set(pattern[1].clone());
+    let four = to_set(pattern[2].clone());
+    let eight = to_set(pattern[9].clone());
+    let nine = &pattern[6..9]
+        .iter()
+        .cloned()
+        .map(to_set)
+        .find(|digit| digit.is_superset(&four))?;
+    let five = &pattern[3..9]
+        .iter()
+        .cloned()
+        .map(to_set)
+        .find(|digit| digit.is_subset(nine) && !digit.is_superset(&one))?;
+    let six = &pattern[6..9]
+        .iter()
+        .cloned()
+        .map(to_set)
+        .find(|digit| digit.is_superset(five) && digit != nine)?;
+    let zero = &pattern[6..9]
+        .iter()
+        .cloned()
+        .map(to_set)
+        .find(|digit| !digit.is_superset(five) && digit != nine)?;
+    let three = &pattern[3..9]
+        .iter()
+        .cloned()
+        .map(to_set)
+        .find(|digit| digit.is_subset(nine) && digit != five)?;
+    let two = &pattern[3..9]
+        .iter()
+        .cloned()
+        .map(to_set)
+        .find(|digit| !digit.is_subset(nine))?;
+    let resolved = vec![
+        zero, &one, two, three, &four, five, six, &seven, &eight, nine,
+    ];
+    output
+        .iter()
+        .map(|digit| {
+            resolved
+                .iter()
+                .enumerate()
+                .find(|(_, x)| **x == &to_set(digit.clone()))
+                .map(|(value, _)| value.to_string())
+        })
+        .collect::<Option<String>>()?
+        .parse::<i32>()
+        .ok()
+}
+
+fn to_set(digit: String) -> HashSet<char> {
+    digit.chars().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce
+";
+
+    #[test]
+    fn part_1_sample() {
+        let input = Day08::parse(SAMPLE).unwrap();
+        assert_eq!(Day08::part_1(&input), 26);
+    }
+
+    #[test]
+    fn part_2_sample() {
+        let input = Day08::parse(SAMPLE).unwrap();
+        assert_eq!(Day08::part_2(&input), Answer::Solved(61229));
+    }
+    #[test]
+    fn decode_single_display() {
+        let display =
+            "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf";
+        let input = Day08::parse(display).unwrap();
+        let (pattern, output) = &input[0];
+        assert_eq!(decode(pattern, output), Some(5353));
+    }
+
+    #[test]
+    fn patterns_that_are_not_digits() {
+        let display = "ab ab ab ab ab ab ab ab ab ab | ab ab ab ab";
+        let input = Day08::parse(display).unwrap();
+        let (pattern, output) = &input[0];
+        assert_eq!(decode(pattern, output), None);
+        let display = "ab abc abcd abcde abcdf abcdg abcdef abcdeg abcdfg abcdefg | ab ab ab ab";
+        let input = Day08::parse(display).unwrap();
+        let (pattern, output) = &input[0];
+        assert_eq!(decode(pattern, output), None);
+        assert!(matches!(Day08::part_2(&input), Answer::Unsolved(_)));
+    }
+}
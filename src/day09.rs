@@ -0,0 +1,79 @@
+use crate::grid::{Grid, Position};
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+/// Puzzle input: height map of the cave floor
+pub type Input = Grid<u8>;
+
+/// Positions lower than every adjacent location
+pub fn low_points(input: &Input) -> impl Iterator<Item = Position> + '_ {
+    input
+        .positions()
+        .filter(|&pos| input.neighbours_4(pos).all(|n| input[pos] < input[n]))
+}
+
+/// Size of the basin flowing down to the given low point
+pub fn basin_size(input: &Input, low_point: Position) -> usize {
+    let mut visited = input.map(|_| false);
+    let mut to_inspect = vec![low_point];
+    let mut size = 0;
+    while let Some(pos) = to_inspect.pop() {
+        if visited[pos] || input[pos] == 9 {
+            continue;
+        }
+        visited[pos] = true;
+        size += 1;
+        to_inspect.extend(input.neighbours_4(pos).filter(|&n| !visited[n]));
+    }
+    size
+}
+
+/// Day 9: Smoke Basin
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Input;
+    type Answer1 = u32;
+    type Answer2 = usize;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse_digits(content)
+    }
+
+    fn part_1(input: &Input) -> Self::Answer1 {
+        low_points(input).map(|pos| input[pos] as u32 + 1).sum()
+    }
+
+    fn part_2(input: &Input) -> Self::Answer2 {
+        let mut basins: Vec<usize> = low_points(input)
+            .map(|pos| basin_size(input, pos))
+            .collect();
+        basins.sort_unstable_by(|a, b| b.cmp(a));
+        basins.into_iter().take(3).product()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+2199943210
+3987894921
+9856789892
+8767896789
+9899965678
+";
+
+    #[test]
+    fn part_1_sample() {
+        let input = Day09::parse(SAMPLE).unwrap();
+        assert_eq!(Day09::part_1(&input), 15);
+    }
+
+    #[test]
+    fn part_2_sample() {
+        let input = Day09::parse(SAMPLE).unwrap();
+        assert_eq!(Day09::part_2(&input), 1134);
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::HashSet;
+use std::ops::Index;
+use std::str::FromStr;
+
+use crate::parse::{self, ParseError};
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug)]
+pub struct Input(pub Vec<String>);
+
+impl Index<usize> for Input {
+    type Output = String;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.0.index(index)
+    }
+}
+
+// Conversion from string to puzzle input
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(content: &str) -> Result<Self, Self::Err> {
+        parse::lines(content, |line| {
+            match line.find(|c| !"()[]{}<>".contains(c)) {
+                Some(i) => {
+                    Err(ParseError::invalid(&line[i..i + 1], "a bracket").within(line, &line[i..]))
+                }
+                None => Ok(line.to_string()),
+            }
+        })
+        .map(Input)
+    }
+}
+
+/// Day 10: Syntax Scoring
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Input;
+    type Answer1 = i32;
+    type Answer2 = Answer<i64>;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        content.parse()
+    }
+
+    fn part_1(input: &Input) -> Self::Answer1 {
+        let openers: HashSet<char> = {
+            let mut h = HashSet::new();
+            h.insert('(');
+            h.insert('{');
+            h.insert('[');
+            h.insert('<');
+            h
+        };
+        input
+            .0
+            .iter()
+            .map(|line| {
+                let mut stack = vec![];
+                for ch in line.chars() {
+                    if openers.contains(&ch) {
+                        stack.push(ch);
+                    } else {
+                        if !closes(stack.pop(), ch) {
+                            return Some(ch);
+                        }
+                    }
+                }
+                None
+            })
+            .fold(0, |acc, char_opt| {
+                if let Some(c) = char_opt {
+                    (match c {
+                        ')' => 3,
+                        ']' => 57,
+                        '}' => 1197,
+                        '>' => 25137,
+                        _ => 0,
+                    }) + acc
+                } else {
+                    acc
+                }
+            })
+    }
+
+    fn part_2(input: &Input) -> Self::Answer2 {
+        let openers: HashSet<char> = {
+            let mut h = HashSet::new();
+            h.insert('(');
+            h.insert('{');
+            h.insert('[');
+            h.insert('<');
+            h
+        };
+        let mut scores = input
+            .0
+            .iter()
+            .filter_map(|line| {
+                let mut stack = vec![];
+                for ch in line.chars() {
+                    if openers.contains(&ch) {
+                        stack.push(ch);
+                    } else {
+                        if !closes(stack.pop(), ch) {
+                            return None;
+                        }
+                    }
+                }
+                stack.reverse();
+                let score = stack.iter().fold(0, |acc, c| {
+                    acc * 5
+                        + (match c {
+                            '(' => 1,
+                            '[' => 2,
+                            '{' => 3,
+                            '<' => 4,
+                            _ => 0,
+                        })
+                });
+                Some(score)
+            })
+            .collect::<Vec<_>>();
+        scores.sort();
+        match scores.len() {
+            0 => Answer::Unsolved(ALL_CORRUPTED.to_string()),
+            n => Answer::Solved(scores[n / 2]),
+        }
+    }
+}
+
+/// Reason given when no line is left to complete
+pub const ALL_CORRUPTED: &str = "every line is corrupted";
+
+// Whether the closing character matches the last opened chunk, if any
+fn closes(last: Option<char>, ch: char) -> bool {
+    last.is_some_and(|last| (0..=2).contains(&(ch as i32 - last as i32)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]
+";
+
+    #[test]
+    fn part_1_sample() {
+        let input = Day10::parse(SAMPLE).unwrap();
+        assert_eq!(Day10::part_1(&input), 26397);
+    }
+
+    #[test]
+    fn part_2_sample() {
+        let input = Day10::parse(SAMPLE).unwrap();
+        assert_eq!(Day10::part_2(&input), Answer::Solved(288957));
+    }
+
+    #[test]
+    fn unopened_chunks_are_corrupted() {
+        let input = Day10::parse(")\n(]\n").unwrap();
+        assert_eq!(Day10::part_1(&input), 3 + 57);
+        assert_eq!(
+            Day10::part_2(&input),
+            Answer::Unsolved(ALL_CORRUPTED.to_string())
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+/// Puzzle input: energy level of every octopus
+pub type Input = Grid<u8>;
+
+/// Advances the octopuses by one step and returns how many of them flashed
+pub fn step(octopuses: &mut Input) -> usize {
+    let mut flashing = vec![];
+    for pos in octopuses.positions() {
+        octopuses[pos] += 1;
+        if octopuses[pos] == 10 {
+            flashing.push(pos);
+        }
+    }
+    while let Some(pos) = flashing.pop() {
+        for n in octopuses.neighbours_8(pos) {
+            octopuses[n] += 1;
+            if octopuses[n] == 10 {
+                flashing.push(n);
+            }
+        }
+    }
+    let mut flashed = 0;
+    for level in octopuses.iter_mut().filter(|level| **level > 9) {
+        *level = 0;
+        flashed += 1;
+    }
+    flashed
+}
+
+/// Day 11: Dumbo Octopus
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse_digits(content)
+    }
+
+    fn part_1(input: &Input) -> Self::Answer1 {
+        let mut octopuses = input.clone();
+        (0..100).map(|_| step(&mut octopuses)).sum()
+    }
+
+    fn part_2(input: &Input) -> Self::Answer2 {
+        let mut octopuses = input.clone();
+        let size = octopuses.height() * octopuses.width();
+        (1..).find(|_| step(&mut octopuses) == size).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526
+";
+
+    #[test]
+    fn part_1_sample() {
+        let input = Day11::parse(SAMPLE).unwrap();
+        assert_eq!(Day11::part_1(&input), 1656);
+    }
+
+    #[test]
+    fn part_2_sample() {
+        let input = Day11::parse(SAMPLE).unwrap();
+        assert_eq!(Day11::part_2(&input), 195);
+    }
+}
@@ -0,0 +1,263 @@
+use std::collections::{HashMap, HashSet};
+use std::ops::Index;
+use std::str::FromStr;
+
+use crate::parse::{self, ParseError};
+use crate::solution::Solution;
+
+#[derive(Debug, Default)]
+pub struct Graph {
+    pub start: NodeIndex,
+    pub end: NodeIndex,
+    pub nodes: Vec<NodeData>,
+}
+
+pub type NodeIndex = usize;
+
+#[derive(Debug)]
+pub struct NodeData {
+    pub name: String,
+    pub neightbors: Vec<NodeIndex>,
+}
+
+impl Graph {
+    pub fn new() -> Graph {
+        Graph {
+            nodes: Vec::new(),
+            start: 0,
+            end: 0,
+        }
+    }
+
+    pub fn add_node(&mut self, name: &str) -> NodeIndex {
+        let index = self.nodes.len();
+        self.nodes.push(NodeData {
+            neightbors: vec![],
+            name: name.to_string(),
+        });
+        index
+    }
+
+    pub fn add_edge(&mut self, from: NodeIndex, to: NodeIndex) {
+        self.nodes[from].neightbors.push(to);
+    }
+}
+
+impl Index<NodeIndex> for Graph {
+    type Output = NodeData;
+
+    fn index(&self, index: NodeIndex) -> &Self::Output {
+        self.nodes.index(index)
+    }
+}
+
+// Conversion from string to puzzle input
+impl FromStr for Graph {
+    type Err = ParseError;
+
+    fn from_str(content: &str) -> Result<Self, Self::Err> {
+        let mut graph = Graph::new();
+        let mut added = HashMap::new();
+        for (i, line) in content.lines().enumerate() {
+            let (left, right) = parse::split_once(line, "-", "a path `from-to`")
+                .and_then(|(left, right)| {
+                    Ok((
+                        cave(left)?,
+                        cave(right).map_err(|err| err.within(line, right))?,
+                    ))
+                })
+                .map_err(|err| err.at_line(i + 1))?;
+            let from = if let Some(from_) = added.get(&left) {
+                *from_
+            } else {
+                let from_ = graph.add_node(left);
+                added.insert(left, from_);
+                from_
+            };
+            let to = if let Some(to_) = added.get(&right) {
+                *to_
+            } else {
+                let to_ = graph.add_node(right);
+                added.insert(right, to_);
+                to_
+            };
+            graph.add_edge(from, to);
+            graph.add_edge(to, from);
+        }
+        for name in ["start", "end"] {
+            if !added.contains_key(name) {
+                return Err(
+                    ParseError::missing("", format!("a path to the `{}` cave", name))
+                        .at_line(content.lines().count() + 1),
+                );
+            }
+        }
+        for (i, node) in graph.nodes.iter().enumerate() {
+            if node.name == "start" {
+                graph.start = i;
+            }
+            if node.name == "end" {
+                graph.end = i;
+            }
+        }
+        Ok(graph)
+    }
+}
+
+// Checks that a cave name is made of letters only
+fn cave(name: &str) -> Result<&str, ParseError> {
+    if !name.is_empty() && name.chars().all(|c| c.is_ascii_alphabetic()) {
+        Ok(name)
+    } else {
+        Err(ParseError::invalid(name, "a cave name"))
+    }
+}
+
+/// Day 12: Passage Pathing
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Graph;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError> {
+        content.parse()
+    }
+
+    fn part_1(input: &Graph) -> Self::Answer1 {
+        let mut to_visit = vec![(HashSet::new(), input.start)];
+        let mut counter = 0;
+        while let Some((past, current)) = to_visit.pop() {
+            for neightbour_index in input[current].neightbors.iter() {
+                if *neightbour_index == input.start {
+                    continue;
+                }
+                if *neightbour_index == input.end {
+                    counter += 1;
+                    continue;
+                }
+                let neightbour = &input[*neightbour_index];
+                if neightbour.name.to_uppercase() == neightbour.name
+                    || (neightbour.name.to_lowercase() == neightbour.name
+                        && !past.contains(neightbour_index))
+                {
+                    let mut new_past = past.clone();
+                    new_past.insert(current);
+                    to_visit.push((new_past, *neightbour_index));
+                }
+            }
+        }
+        counter
+    }
+
+    fn part_2(input: &Graph) -> Self::Answer2 {
+        let mut to_visit = vec![(Vec::new(), None, input.start)];
+        let mut paths = HashSet::new();
+        while let Some((past, twice, current)) = to_visit.pop() {
+            if current == input.start && !past.is_empty() {
+                continue;
+            }
+            if current == input.end {
+                paths.insert(past);
+                continue;
+            }
+            let node = &input[current];
+            let is_upper = node.name.to_uppercase() == node.name;
+
+            let mut new_past = past.clone();
+            new_past.push(current);
+            let count = past.iter().filter(|x| **x == current).count();
+            match (is_upper, count, twice) {
+                (true, _, _) => {
+                    for neightbour in node.neightbors.iter() {
+                        to_visit.push((new_past.clone(), twice, *neightbour));
+                    }
+                }
+                (_, 0, Some(x)) | (_, 1, Some(x)) if x == current => {
+                    for neightbour in node.neightbors.iter() {
+                        to_visit.push((new_past.clone(), twice, *neightbour));
+                    }
+                }
+                (_, 0, Some(x)) if x != current => {
+                    for neightbour in node.neightbors.iter() {
+                        to_visit.push((new_past.clone(), twice, *neightbour));
+                    }
+                }
+                (_, 0, None) => {
+                    for neightbour in node.neightbors.iter() {
+                        to_visit.push((new_past.clone(), Some(current), *neightbour));
+                        to_visit.push((new_past.clone(), None, *neightbour));
+                    }
+                }
+                _ => {}
+            }
+        }
+        paths.len() as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL: &str = "\
+start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end
+";
+
+    const MEDIUM: &str = "\
+dc-end
+HN-start
+start-kj
+dc-start
+dc-HN
+LN-dc
+HN-end
+kj-sa
+kj-HN
+kj-dc
+";
+
+    const LARGE: &str = "\
+fs-end
+he-DX
+fs-he
+start-DX
+pj-DX
+end-zg
+zg-sl
+zg-pj
+pj-he
+RW-he
+fs-DX
+pj-RW
+zg-RW
+start-pj
+he-WI
+zg-he
+pj-fs
+start-RW
+";
+
+    #[test]
+    fn part_1_samples() {
+        for (sample, expected) in [(SMALL, 10), (MEDIUM, 19), (LARGE, 226)] {
+            let input = Day12::parse(sample).unwrap();
+            assert_eq!(Day12::part_1(&input), expected);
+        }
+    }
+
+    #[test]
+    fn part_2_samples() {
+        for (sample, expected) in [(SMALL, 36), (MEDIUM, 103), (LARGE, 3509)] {
+            let input = Day12::parse(sample).unwrap();
+            assert_eq!(Day12::part_2(&input), expected);
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::parse::{self, ParseError};
+
+/// Position of a cell as `(row, column)`
+pub type Position = (usize, usize);
+
+/// Rectangular grid of cells stored row after row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    height: usize,
+    width: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its cells given row after row
+    pub fn new(height: usize, width: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), height * width, "grid cells do not fill it");
+        Grid {
+            height,
+            width,
+            cells,
+        }
+    }
+
+    /// Parses a map with one cell per character, all lines having the same length
+    pub fn parse_with(
+        content: &str,
+        expected: &str,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let width = content
+            .lines()
+            .next()
+            .map_or(0, |line| line.chars().count());
+        let rows: Vec<Vec<T>> = parse::lines(content, |line| {
+            if line.chars().count() != width {
+                return Err(ParseError::invalid(
+                    line,
+                    format!("a row of {} cells", width),
+                ));
+            }
+            Result::from_iter(line.chars().enumerate().map(|(i, c)| {
+                cell(c).ok_or_else(|| ParseError::invalid(&c.to_string(), expected).shift(i))
+            }))
+        })?;
+        let height = rows.len();
+        Ok(Grid::new(
+            height,
+            width,
+            rows.into_iter().flatten().collect(),
+        ))
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Cell at the given position, if it lies within the grid
+    pub fn get(&self, (row, col): Position) -> Option<&T> {
+        if row < self.height && col < self.width {
+            self.cells.get(row * self.width + col)
+        } else {
+            None
+        }
+    }
+
+    /// Mutable cell at the given position, if it lies within the grid
+    pub fn get_mut(&mut self, (row, col): Position) -> Option<&mut T> {
+        if row < self.height && col < self.width {
+            self.cells.get_mut(row * self.width + col)
+        } else {
+            None
+        }
+    }
+
+    /// Every position of the grid, row after row
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// Every cell of the grid, row after row
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Every cell of the grid mutably, row after row
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// Cells of a row, from left to right
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    /// Every row, from top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |row| self.row(row))
+    }
+
+    /// Cells of a column, from top to bottom
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "column {} out of the grid", col);
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    /// Every column, from left to right
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |col| self.column(col))
+    }
+
+    /// Positions next to the given one horizontally or vertically
+    pub fn neighbours_4(&self, position: Position) -> impl Iterator<Item = Position> {
+        self.offsets(position, &[(-1, 0), (0, -1), (0, 1), (1, 0)])
+    }
+
+    /// Positions next to the given one, diagonals included
+    pub fn neighbours_8(&self, position: Position) -> impl Iterator<Item = Position> {
+        self.offsets(
+            position,
+            &[
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+                (0, -1),
+                (0, 1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+            ],
+        )
+    }
+
+    /// Builds a grid of the same shape from the cells of this one
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid::new(self.height, self.width, self.cells.iter().map(f).collect())
+    }
+
+    // Positions at the given offsets that lie within the grid
+    fn offsets(
+        &self,
+        (row, col): Position,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Position> {
+        let (height, width) = (self.height, self.width);
+        offsets.iter().filter_map(move |(d_row, d_col)| {
+            let row = row.checked_add_signed(*d_row)?;
+            let col = col.checked_add_signed(*d_col)?;
+            (row < height && col < width).then_some((row, col))
+        })
+    }
+}
+
+impl Grid<u8> {
+    /// Parses a map of single digits
+    pub fn parse_digits(content: &str) -> Result<Self, ParseError> {
+        Grid::parse_with(content, "a digit", |c| c.to_digit(10).map(|d| d as u8))
+    }
+}
+
+impl Grid<char> {
+    /// Parses a map of characters
+    pub fn parse_chars(content: &str) -> Result<Self, ParseError> {
+        Grid::parse_with(content, "a character", Some)
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        self.get(position)
+            .unwrap_or_else(|| panic!("position {:?} out of the grid", position))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("position {:?} out of the grid", position))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIGITS: &str = "\
+123
+456
+";
+
+    #[test]
+    fn parse_and_display() {
+        let grid = Grid::parse_digits(DIGITS).unwrap();
+        assert_eq!((grid.height(), grid.width()), (2, 3));
+        assert_eq!(grid[(1, 2)], 6);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.to_string(), DIGITS);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = Grid::parse_digits("123\n4x6\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 2));
+        let err = Grid::parse_digits("123\n45\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 1));
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = Grid::parse_digits(DIGITS).unwrap();
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[1, 2, 3], [4, 5, 6]]);
+        let columns: Vec<Vec<u8>> = grid.columns().map(|col| col.copied().collect()).collect();
+        assert_eq!(columns, [[1, 4], [2, 5], [3, 6]]);
+    }
+
+    #[test]
+    fn neighbours_stay_within_the_grid() {
+        let grid = Grid::parse_digits(DIGITS).unwrap();
+        let corner: Vec<Position> = grid.neighbours_4((0, 0)).collect();
+        assert_eq!(corner, [(0, 1), (1, 0)]);
+        let middle: Vec<Position> = grid.neighbours_8((0, 1)).collect();
+        assert_eq!(middle, [(0, 0), (0, 2), (1, 0), (1, 1), (1, 2)]);
+    }
+}
@@ -0,0 +1,64 @@
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::PathBuf;
+
+/// Environment variable overriding the directory holding the puzzle inputs
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Directory holding the puzzle inputs when the environment does not say otherwise
+pub const DEFAULT_INPUT_DIR: &str = "inputs";
+
+/// Where the puzzle input of a day is read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Source named on the command line, `-` standing for the standard input
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+
+    /// Default input file of a day, looked up in `AOC_INPUT_DIR` or `inputs/`
+    pub fn for_day(number: u32) -> Self {
+        let dir = std::env::var_os(INPUT_DIR_VAR)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR));
+        InputSource::File(dir.join(format!("day{}.txt", number)))
+    }
+
+    /// Opens the puzzle input for reading it line by line as it arrives
+    pub fn open(&self) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            InputSource::File(path) => Ok(Box::new(BufReader::new(std::fs::File::open(path)?))),
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+        }
+    }
+
+    /// Reads the whole puzzle input
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            InputSource::File(path) => std::fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut content = String::new();
+                io::stdin().read_to_string(&mut content)?;
+                Ok(content)
+            }
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+//! Solutions to the Advent of Code 2021 puzzles
+//!
+//! Every day lives in its own module exposing its input parser and a type
+//! implementing [`Solution`](solution::Solution). The [`registry`] lists all
+//! of them for tools that run several days at once.
+
+pub mod answers;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod registry;
+pub mod solution;
@@ -0,0 +1,206 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Error raised when a puzzle input does not follow its expected format
+///
+/// Lines and columns are 1-based. Parsers of a single token or line report
+/// positions relative to it, and the enclosing parser moves the error to its
+/// place in the whole input with [`ParseError::at_line`] and
+/// [`ParseError::shift`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// Some text was found where something else was expected
+    Invalid {
+        line: usize,
+        column: usize,
+        text: String,
+        expected: String,
+    },
+    /// The line or the input ended before something that was expected
+    Missing {
+        line: usize,
+        column: usize,
+        expected: String,
+    },
+}
+
+impl ParseError {
+    /// Error for a token that does not match the expected format
+    pub fn invalid(text: &str, expected: impl Into<String>) -> Self {
+        ParseError::Invalid {
+            line: 1,
+            column: 1,
+            text: text.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Error for something expected right after the end of `text`
+    pub fn missing(text: &str, expected: impl Into<String>) -> Self {
+        ParseError::Missing {
+            line: 1,
+            column: text.chars().count() + 1,
+            expected: expected.into(),
+        }
+    }
+
+    /// Line at which the error occurred
+    pub fn line(&self) -> usize {
+        match self {
+            ParseError::Invalid { line, .. } | ParseError::Missing { line, .. } => *line,
+        }
+    }
+
+    /// Column at which the error occurred
+    pub fn column(&self) -> usize {
+        match self {
+            ParseError::Invalid { column, .. } | ParseError::Missing { column, .. } => *column,
+        }
+    }
+
+    /// Places the error on the given line of the input
+    pub fn at_line(mut self, number: usize) -> Self {
+        match &mut self {
+            ParseError::Invalid { line, .. } | ParseError::Missing { line, .. } => *line = number,
+        }
+        self
+    }
+
+    /// Moves the error right by the given number of columns
+    pub fn shift(mut self, columns: usize) -> Self {
+        match &mut self {
+            ParseError::Invalid { column, .. } | ParseError::Missing { column, .. } => {
+                *column += columns
+            }
+        }
+        self
+    }
+
+    /// Moves an error reported relative to `part` to its place in `whole`
+    pub fn within(self, whole: &str, part: &str) -> Self {
+        self.shift(column_of(whole, part) - 1)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Invalid {
+                line,
+                column,
+                text,
+                expected,
+            } => write!(
+                f,
+                "line {}, column {}: expected {}, found `{}`",
+                line, column, expected, text
+            ),
+            ParseError::Missing {
+                line,
+                column,
+                expected,
+            } => write!(f, "line {}, column {}: expected {}", line, column, expected),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Column at which `part`, a slice borrowed from `whole`, starts
+pub fn column_of(whole: &str, part: &str) -> usize {
+    let offset = part.as_ptr() as usize - whole.as_ptr() as usize;
+    whole[..offset].chars().count() + 1
+}
+
+/// Parses a number, reporting `expected` when the text is not one
+pub fn number<T: FromStr>(text: &str, expected: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::invalid(text, expected))
+}
+
+/// Parses every line of the input, placing errors on the line they come from
+pub fn lines<T>(
+    content: &str,
+    parse: impl Fn(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    content
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse(line).map_err(|err| err.at_line(i + 1)))
+        .collect()
+}
+
+/// Parses the items of a list separated by `separator`, placing errors in `text`
+pub fn separated<T>(
+    text: &str,
+    separator: &str,
+    parse: impl Fn(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    text.split(separator)
+        .map(|item| parse(item).map_err(|err| err.within(text, item)))
+        .collect()
+}
+
+/// Splits the text around the first `delimiter`, reporting `expected` when it is absent
+pub fn split_once<'a>(
+    text: &'a str,
+    delimiter: &str,
+    expected: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(delimiter)
+        .ok_or_else(|| ParseError::missing(text, expected))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn columns_count_characters() {
+        let whole = "é→ x";
+        let part = &whole[whole.find('x').unwrap()..];
+        assert_eq!(column_of(whole, whole), 1);
+        assert_eq!(column_of(whole, part), 4);
+        assert_eq!(ParseError::missing("é→", "a space").column(), 3);
+    }
+
+    #[test]
+    fn shift_and_within_move_the_column() {
+        let err = ParseError::invalid("x", "a digit").shift(4);
+        assert_eq!((err.line(), err.column()), (1, 5));
+        let line = "ä = [1, ü]";
+        let list = &line[line.find('[').unwrap()..];
+        let item = &list[list.find('ü').unwrap()..list.len() - 1];
+        let err = ParseError::invalid(item, "a number")
+            .within(list, item)
+            .within(line, list)
+            .at_line(3);
+        assert_eq!((err.line(), err.column()), (3, 9));
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 9: expected a number, found `ü`"
+        );
+    }
+
+    #[test]
+    fn separated_places_errors_in_the_list() {
+        let parse = |item: &str| number::<u8>(item, "a number");
+        assert_eq!(separated("1,2,3", ",", parse), Ok(vec![1, 2, 3]));
+        let err = separated("1,→,3", ",", parse).unwrap_err();
+        assert_eq!(err.column(), 3);
+        let words = |item: &str| match item {
+            "x" => Err(ParseError::invalid(item, "a word")),
+            _ => Ok(item.chars().count()),
+        };
+        let err = separated("→→, x", ", ", words).unwrap_err();
+        assert_eq!(err.column(), 5);
+    }
+
+    #[test]
+    fn lines_places_errors_on_their_line() {
+        let parse = |line: &str| separated(line, " ", |item| number::<i32>(item, "a number"));
+        assert_eq!(lines("1 2\n3\n", parse), Ok(vec![vec![1, 2], vec![3]]));
+        let err = lines("1 2\n3 ß 4\n", parse).unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 3));
+    }
+}
@@ -0,0 +1,242 @@
+use std::fmt::Display;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::day01::Day01;
+use crate::day02::Day02;
+use crate::day03::Day03;
+use crate::day04::Day04;
+use crate::day05::Day05;
+use crate::day06::Day06;
+use crate::day07::Day07;
+use crate::day08::Day08;
+use crate::day09::Day09;
+use crate::day10::Day10;
+use crate::day11::Day11;
+use crate::day12::Day12;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+/// A puzzle day the runner knows how to solve
+pub struct Day {
+    pub number: u32,
+    /// Parses the input once then solves each part the given number of times
+    pub run: fn(&str, usize) -> Result<Report, ParseError>,
+}
+
+/// Answers of a day along with the time spent computing them
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub parse: Duration,
+    pub parts: [PartReport; 2],
+}
+
+/// Answer of a part and the duration of every run that computed it
+#[derive(Debug, Clone)]
+pub struct PartReport {
+    pub answer: String,
+    pub times: Vec<Duration>,
+}
+
+impl Report {
+    /// Answers of both parts
+    pub fn answers(&self) -> [String; 2] {
+        self.parts.clone().map(|part| part.answer)
+    }
+}
+
+impl PartReport {
+    /// Fastest run
+    pub fn min(&self) -> Duration {
+        self.times.iter().copied().min().unwrap_or_default()
+    }
+
+    /// Median run, averaging the two middle ones for an even number of runs
+    pub fn median(&self) -> Duration {
+        let mut times = self.times.clone();
+        times.sort();
+        match times.len() {
+            0 => Duration::ZERO,
+            n if n % 2 == 0 => (times[n / 2 - 1] + times[n / 2]) / 2,
+            n => times[n / 2],
+        }
+    }
+
+    /// Average run
+    pub fn mean(&self) -> Duration {
+        match self.times.len() {
+            0 => Duration::ZERO,
+            n => self.times.iter().sum::<Duration>() / n as u32,
+        }
+    }
+}
+
+/// Parses the puzzle input and computes the answer of both parts, timing every step
+fn solve<S: Solution>(content: &str, runs: usize) -> Result<Report, ParseError> {
+    let start = Instant::now();
+    let input = S::parse(content)?;
+    let parse = start.elapsed();
+    Ok(Report {
+        parse,
+        parts: [
+            time(runs, || S::part_1(black_box(&input))),
+            time(runs, || S::part_2(black_box(&input))),
+        ],
+    })
+}
+
+// Runs a part at least once and records how long each run took
+fn time<T: Display>(runs: usize, part: impl Fn() -> T) -> PartReport {
+    let mut times = vec![];
+    let mut answer = None;
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        let result = part();
+        times.push(start.elapsed());
+        answer = Some(result);
+    }
+    PartReport {
+        answer: answer.map(|answer| answer.to_string()).unwrap_or_default(),
+        times,
+    }
+}
+
+/// Every solved day, in order
+pub const DAYS: [Day; 12] = [
+    Day {
+        number: 1,
+        run: solve::<Day01>,
+    },
+    Day {
+        number: 2,
+        run: solve::<Day02>,
+    },
+    Day {
+        number: 3,
+        run: solve::<Day03>,
+    },
+    Day {
+        number: 4,
+        run: solve::<Day04>,
+    },
+    Day {
+        number: 5,
+        run: solve::<Day05>,
+    },
+    Day {
+        number: 6,
+        run: solve::<Day06>,
+    },
+    Day {
+        number: 7,
+        run: solve::<Day07>,
+    },
+    Day {
+        number: 8,
+        run: solve::<Day08>,
+    },
+    Day {
+        number: 9,
+        run: solve::<Day09>,
+    },
+    Day {
+        number: 10,
+        run: solve::<Day10>,
+    },
+    Day {
+        number: 11,
+        run: solve::<Day11>,
+    },
+    Day {
+        number: 12,
+        run: solve::<Day12>,
+    },
+];
+
+/// Finds a day in the registry from its number
+pub fn find(number: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+/// Resolves a day selection such as `5`, `1..12` (inclusive) or `all`
+pub fn select(selection: &str) -> Result<Vec<&'static Day>, String> {
+    if selection == "all" {
+        return Ok(DAYS.iter().collect());
+    }
+    let parse = |number: &str| {
+        number
+            .parse::<u32>()
+            .map_err(|_| format!("invalid day `{}`", number))
+    };
+    let (from, to) = match selection.split_once("..") {
+        Some((from, to)) => (parse(from)?, parse(to.trim_start_matches('='))?),
+        None => (parse(selection)?, parse(selection)?),
+    };
+    if from > to {
+        return Err(format!("empty day range `{}`", selection));
+    }
+    (from..=to)
+        .map(|number| find(number).ok_or(format!("day {} is not solved", number)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn part(millis: &[u64]) -> PartReport {
+        PartReport {
+            answer: String::new(),
+            times: millis.iter().copied().map(Duration::from_millis).collect(),
+        }
+    }
+
+    fn numbers(days: Vec<&Day>) -> Vec<u32> {
+        days.into_iter().map(|day| day.number).collect()
+    }
+
+    #[test]
+    fn statistics_of_runs() {
+        let odd = part(&[9, 1, 5]);
+        assert_eq!(odd.min(), Duration::from_millis(1));
+        assert_eq!(odd.median(), Duration::from_millis(5));
+        assert_eq!(odd.mean(), Duration::from_millis(5));
+        let even = part(&[8, 1, 4, 3]);
+        assert_eq!(even.median(), Duration::from_micros(3500));
+        assert_eq!(even.mean(), Duration::from_millis(4));
+        let empty = part(&[]);
+        assert_eq!(
+            (empty.min(), empty.median(), empty.mean()),
+            (Duration::ZERO, Duration::ZERO, Duration::ZERO)
+        );
+    }
+
+    #[test]
+    fn day_selections() {
+        assert_eq!(select("5").map(numbers), Ok(vec![5]));
+        assert_eq!(select("1..12").map(numbers), Ok((1..=12).collect()));
+        assert_eq!(select("1..=12").map(numbers), Ok((1..=12).collect()));
+        assert_eq!(select("3..3").map(numbers), Ok(vec![3]));
+        assert_eq!(select("all").map(numbers), Ok((1..=12).collect()));
+    }
+
+    #[test]
+    fn invalid_day_selections() {
+        assert_eq!(
+            select("12..1").map(numbers),
+            Err("empty day range `12..1`".to_string())
+        );
+        assert_eq!(
+            select("13").map(numbers),
+            Err("day 13 is not solved".to_string())
+        );
+        assert_eq!(
+            select("10..14").map(numbers),
+            Err("day 13 is not solved".to_string())
+        );
+        assert_eq!(
+            select("x..2").map(numbers),
+            Err("invalid day `x`".to_string())
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use std::fmt::{self, Display};
+
+use crate::parse::ParseError;
+
+/// A puzzle day: how to parse its input and how to solve both of its parts
+pub trait Solution {
+    /// Parsed puzzle input shared by both parts
+    type Input;
+    /// Answer of the first part
+    type Answer1: Display;
+    /// Answer of the second part
+    type Answer2: Display;
+
+    fn parse(content: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_1(input: &Self::Input) -> Self::Answer1;
+
+    fn part_2(input: &Self::Input) -> Self::Answer2;
+}
+
+/// Answer of a part, or the reason why an input that parsed correctly has none
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer<T> {
+    Solved(T),
+    Unsolved(String),
+}
+
+impl<T, E: Display> From<Result<T, E>> for Answer<T> {
+    fn from(result: Result<T, E>) -> Self {
+        match result {
+            Ok(answer) => Answer::Solved(answer),
+            Err(reason) => Answer::Unsolved(reason.to_string()),
+        }
+    }
+}
+
+impl<T: Display> Display for Answer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Solved(answer) => answer.fmt(f),
+            Answer::Unsolved(reason) => write!(f, "no answer: {}", reason),
+        }
+    }
+}